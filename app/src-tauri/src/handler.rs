@@ -1,14 +1,19 @@
 use crate::mouse::{
-    get_backlight, get_battery_status, get_battery_status_with_handle, get_dpi_stages,
-    get_led_rgb, is_mouse_charging_with_handle, is_mouse_charging, set_backlight, set_backlight_with_handle,
-    set_dpi_stages, set_dpi_stages_with_handle, set_dpi_xy, set_dpi_xy_with_handle,
-    set_matrix_backlight_static, set_matrix_backlight_static_with_handle, set_polling_rate,
-    set_polling_rate_with_handle,
+    apply_charging_effect, apply_lighting_effect, dpi_stages_from_device, execute_on_mouse, find_connected_device,
+    find_connected_mouse, find_polling_device, open_dock, open_mouse, set_dpi_stages_command, with_dock, with_mouse,
+    Mouse,
 };
-use driver::settings::{DpiStage, MouseSettings};
-use driver::{PlatformPreferencesDriver, PlatformUsbDriver, PreferencesDriver, UsbDriver};
+use driver::settings::{
+    uniform_brightness, ChargingLed, DpiStage, LedZone, LightingEffect,
+    MouseSettings,
+};
+use driver::{PlatformPreferencesDriver, PreferencesDriver, RazerDevice};
 use log::{error, info};
-use razer::{RAZER_BASILISK_V3_PRO_ID, RAZER_USB_VENDOR_ID};
+use razer::{
+    Command, DeviceKind, ScrollMode, GetBatteryLevel, GetChargingState, GetDpiStages, GetLedRgb, MatrixEffect,
+    SetDpiXy, BACKLIGHT_LED, ZERO_LED,
+};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use tauri::{AppHandle, Manager};
 
@@ -22,35 +27,38 @@ struct RgbColor {
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 struct DeviceInfo {
+    firmware_version: Option<String>,
+    serial_number: Option<String>,
     battery_level: u8,
     is_charging: bool,
+    /// Charging state reported by the Mouse Dock Pro, `None` without a dock.
+    dock_charging: Option<bool>,
     polling_rate: u16,
+    supported_polling_rates: Vec<u16>,
     dpi_xy: [u16; 2],
     backlight_brightness: u8,
+    led_brightness: BTreeMap<LedZone, u8>,
     backlight_color: RgbColor,
     matrix_behavior: String,
     target_os: String,
     smart_wheel_enabled: bool,
+    scroll_acceleration: bool,
+    free_spin: bool,
+    idle_timeout_secs: Option<u16>,
+    low_battery_threshold: Option<u8>,
     mouse_wheel_inverted: bool,
     dpi_stages: Vec<DpiStage>,
 }
 
 pub unsafe fn ensure_mouse_exists() -> bool {
-    let device_list = PlatformUsbDriver::list_devices();
-
-    let razer_device = device_list.iter().find(|dev| {
-        dev.product_id == RAZER_BASILISK_V3_PRO_ID as u32
-            && dev.vendor_id == RAZER_USB_VENDOR_ID as u32
-    });
-
-    razer_device.is_some()
+    find_connected_mouse().is_some()
 }
 
 #[tauri::command]
 pub fn get_device_information(app: AppHandle) -> Result<String, String> {
     unsafe {
         let mut usb_handle =
-            match PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID) {
+            match open_mouse() {
                 Ok(h) => h,
                 Err(e) => {
                     let err_msg = format!("Failed to open USB device: {}. Please check if the device is connected and you have the necessary permissions.", e);
@@ -59,8 +67,8 @@ pub fn get_device_information(app: AppHandle) -> Result<String, String> {
                 }
             };
 
-        let battery_status = match get_battery_status_with_handle(&mut usb_handle) {
-            Ok(s) => s,
+        let battery_status = match usb_handle.execute(GetBatteryLevel) {
+            Ok(level) => level.percentage(),
             Err(e) => {
                 error!("Failed to get battery status: {}", e);
                 0 // Fallback or return error? Let's return error for now to be safe
@@ -68,14 +76,21 @@ pub fn get_device_information(app: AppHandle) -> Result<String, String> {
             }
         };
 
-        let is_charging = match is_mouse_charging_with_handle(&mut usb_handle) {
-            Ok(c) => c,
+        let is_charging = match usb_handle.execute(GetChargingState) {
+            Ok(state) => state.charging,
             Err(e) => {
                 error!("Failed to get charging status: {}", e);
                 false
             }
         };
 
+        let supported_polling_rates = find_polling_device()
+            .unwrap_or(usb_handle.descriptor())
+            .polling_rates
+            .to_vec();
+        let firmware_version = usb_handle.firmware_version().ok().map(|v| v.to_string());
+        let serial_number = usb_handle.serial_number().ok();
+
         // Load saved settings to ensure UI is in sync with persistent state
         let settings = get_saved_settings(app).unwrap_or_default();
 
@@ -83,6 +98,8 @@ pub fn get_device_information(app: AppHandle) -> Result<String, String> {
 
         drop(usb_handle);
 
+        let dock_charging = with_dock(|d| d.execute(GetChargingState)).ok().map(|state| state.charging);
+
         // Check if natural scrolling (mouse wheel inversion) state is in sync with OS preference
         let current_os_inverted = PlatformPreferencesDriver::is_mouse_wheel_inverted()
             .unwrap_or(settings.scroll_inverted);
@@ -101,19 +118,32 @@ pub fn get_device_information(app: AppHandle) -> Result<String, String> {
         }
 
         let device_info = DeviceInfo {
+            firmware_version,
+            serial_number,
             battery_level: battery_status,
             is_charging,
+            dock_charging,
             polling_rate: settings.polling_rate,
+            supported_polling_rates,
             dpi_xy: [settings.dpi_x, settings.dpi_y],
-            backlight_brightness: settings.brightness,
+            backlight_brightness: settings
+                .brightness
+                .get(&LedZone::Backlight)
+                .copied()
+                .unwrap_or(100),
+            led_brightness: settings.brightness.clone(),
             backlight_color: RgbColor {
                 r: settings.rgb_color[0],
                 g: settings.rgb_color[1],
                 b: settings.rgb_color[2],
             },
-            matrix_behavior: "static".to_string(),
+            matrix_behavior: settings.current_lighting_effect().name().to_string(),
             target_os,
             smart_wheel_enabled: settings.smart_wheel_enabled,
+            scroll_acceleration: settings.scroll_acceleration,
+            free_spin: settings.scroll_mode == ScrollMode::FreeSpin,
+            idle_timeout_secs: settings.idle_timeout_secs,
+            low_battery_threshold: settings.low_battery_threshold,
             mouse_wheel_inverted: settings.scroll_inverted,
             dpi_stages: settings.dpi_stages,
         };
@@ -122,25 +152,96 @@ pub fn get_device_information(app: AppHandle) -> Result<String, String> {
     }
 }
 
-// #[tauri::command]
-// pub fn set_device_smart_wheel(app: AppHandle, enabled: bool) -> Result<(), String> {
-//     // Note: Implementation of hardware protocol for smart wheel is pending
-//     // For now, we persist the setting so the UI remains consistent
-//     let res = update_settings(app, |s| s.smart_wheel_enabled = enabled);
-//     if res.is_ok() {
-//         let msg = format!(
-//             "Smart Wheel setting successfully {} (Persistence only)",
-//             if enabled { "enabled" } else { "disabled" }
-//         );
-//         log::info!("{}", msg);
-//         println!("{}", msg);
-//     }
-//     res
-// }
+#[tauri::command]
+pub fn set_device_smart_wheel(app: AppHandle, enabled: bool) -> Result<(), String> {
+    unsafe {
+        with_mouse(|m| m.set_smart_reel(enabled))?;
+    }
+    let msg = format!(
+        "Smart Wheel successfully {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.smart_wheel_enabled = enabled)
+}
+
+#[tauri::command]
+pub fn get_device_smart_wheel() -> Result<bool, String> {
+    unsafe { with_mouse(|m| m.smart_reel()) }
+}
+
+#[tauri::command]
+pub fn set_device_scroll_acceleration(app: AppHandle, enabled: bool) -> Result<(), String> {
+    unsafe {
+        with_mouse(|m| m.set_scroll_acceleration(enabled))?;
+    }
+    let msg = format!(
+        "Scroll acceleration successfully {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.scroll_acceleration = enabled)
+}
+
+#[tauri::command]
+pub fn get_device_scroll_acceleration() -> Result<bool, String> {
+    unsafe { with_mouse(|m| m.scroll_acceleration()) }
+}
+
+#[tauri::command]
+pub fn set_device_free_spin(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mode = if enabled { ScrollMode::FreeSpin } else { ScrollMode::Tactile };
+    unsafe {
+        with_mouse(|m| m.set_scroll_mode(mode))?;
+    }
+    let msg = format!("Scroll mode successfully set to {:?}", mode);
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.scroll_mode = mode)
+}
+
+#[tauri::command]
+pub fn get_device_free_spin() -> Result<bool, String> {
+    unsafe { with_mouse(|m| m.scroll_mode()) }.map(|mode| mode == ScrollMode::FreeSpin)
+}
+
+#[tauri::command]
+pub fn set_device_idle_timeout(app: AppHandle, seconds: u16) -> Result<(), String> {
+    unsafe {
+        with_mouse(|m| m.set_idle_timeout(seconds))?;
+    }
+    let msg = format!("Idle timeout successfully set to {}s", seconds);
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.idle_timeout_secs = Some(seconds))
+}
+
+#[tauri::command]
+pub fn get_device_idle_timeout() -> Result<u16, String> {
+    unsafe { with_mouse(|m| m.idle_timeout()) }
+}
+
+#[tauri::command]
+pub fn set_device_low_battery_threshold(app: AppHandle, percentage: u8) -> Result<(), String> {
+    unsafe {
+        with_mouse(|m| m.set_low_battery_threshold(percentage))?;
+    }
+    let msg = format!("Low battery threshold successfully set to {}%", percentage);
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.low_battery_threshold = Some(percentage))
+}
+
+#[tauri::command]
+pub fn get_device_low_battery_threshold() -> Result<u8, String> {
+    unsafe { with_mouse(|m| m.low_battery_threshold()) }
+}
 
 #[tauri::command]
 pub fn get_device_battery_status() -> Result<u8, String> {
-    let res = unsafe { get_battery_status() };
+    let res = unsafe { execute_on_mouse(GetBatteryLevel) }.map(|level| level.percentage());
     if let Ok(level) = res {
         let msg = format!("Battery status updated: {}%", level);
         log::info!("{}", msg);
@@ -151,7 +252,7 @@ pub fn get_device_battery_status() -> Result<u8, String> {
 
 #[tauri::command]
 pub fn get_device_charging_status() -> Result<bool, String> {
-    let res = unsafe { is_mouse_charging() };
+    let res = unsafe { execute_on_mouse(GetChargingState) }.map(|state| state.charging);
     if let Ok(charging) = res {
         let msg = format!("Charging status updated: {}", charging);
         log::info!("{}", msg);
@@ -163,8 +264,11 @@ pub fn get_device_charging_status() -> Result<bool, String> {
 #[tauri::command]
 pub fn set_device_dpi(app: AppHandle, dpi_x: u16, dpi_y: u16) -> Result<(), String> {
     unsafe {
-        set_dpi_xy(dpi_x, dpi_y)?;
+        execute_on_mouse(SetDpiXy::new(dpi_x, dpi_y)?)?;
     }
+    let msg = format!("DPI successfully set to {}x{}", dpi_x, dpi_y);
+    log::info!("{}", msg);
+    println!("{}", msg);
     update_settings(app, |s| {
         s.dpi_x = dpi_x;
         s.dpi_y = dpi_y;
@@ -174,21 +278,48 @@ pub fn set_device_dpi(app: AppHandle, dpi_x: u16, dpi_y: u16) -> Result<(), Stri
 #[tauri::command]
 pub fn set_device_backlight_brightness(app: AppHandle, brightness: u8) -> Result<(), String> {
     unsafe {
-        set_backlight(brightness)?;
+        with_mouse(|m| m.set_led_brightness(ZERO_LED, brightness))?;
     }
-    update_settings(app, |s| s.brightness = brightness)
+    let msg = format!("Backlight brightness successfully set to {}%", brightness);
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.brightness = uniform_brightness(brightness))
 }
 
 #[tauri::command]
 pub fn get_device_backlight_brightness() -> Result<u8, String> {
-    unsafe { get_backlight() }
+    unsafe { with_mouse(|m| m.led_brightness(ZERO_LED)) }
+}
+
+#[tauri::command]
+pub fn set_device_led_brightness(app: AppHandle, zone: LedZone, brightness: u8) -> Result<(), String> {
+    unsafe {
+        with_mouse(|m| m.set_led_brightness(zone.led_id(), brightness))?;
+    }
+    let msg = format!("{:?} brightness successfully set to {}%", zone, brightness);
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| {
+        s.brightness.insert(zone, brightness);
+    })
+}
+
+#[tauri::command]
+pub fn get_device_led_brightness(zone: LedZone) -> Result<u8, String> {
+    unsafe { with_mouse(|m| m.led_brightness(zone.led_id())) }
 }
 
 #[tauri::command]
 pub fn set_device_polling_rate(app: AppHandle, polling_rate: u16) -> Result<(), String> {
     unsafe {
-        set_polling_rate(polling_rate)?;
+        let descriptor = find_polling_device().ok_or_else(|| "No supported Razer mouse connected".to_string())?;
+        RazerDevice::open(descriptor)
+            .and_then(|mut d| d.set_polling_rate(polling_rate))
+            .map_err(|e| e.to_string())?;
     }
+    let msg = format!("Polling rate successfully set to {}Hz", polling_rate);
+    log::info!("{}", msg);
+    println!("{}", msg);
     update_settings(app, |s| s.polling_rate = polling_rate)
 }
 
@@ -200,26 +331,106 @@ pub fn set_device_matrix_backlight_static(
     b: u8,
 ) -> Result<(), String> {
     unsafe {
-        set_matrix_backlight_static([r, g, b])?;
+        with_mouse(|m| m.set_lighting_effect(ZERO_LED, MatrixEffect::Static([r, g, b])))?;
+    }
+    let msg = format!(
+        "Matrix backlight successfully set to static RGB: [{}, {}, {}]",
+        r, g, b
+    );
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| {
+        s.rgb_color = [r, g, b];
+        s.lighting_effect = Some(LightingEffect::Static { rgb: [r, g, b] });
+    })
+}
+
+#[tauri::command]
+pub fn set_device_lighting_effect(app: AppHandle, effect: LightingEffect) -> Result<(), String> {
+    unsafe {
+        let mut usb_handle = open_mouse()?;
+        apply_lighting_effect(&mut usb_handle, &effect)?;
+    }
+    let msg = format!("Lighting effect successfully set to {}", effect.name());
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.lighting_effect = Some(effect))
+}
+
+#[tauri::command]
+pub fn set_device_charging_effect(app: AppHandle, state: ChargingLed, effect: LightingEffect) -> Result<(), String> {
+    unsafe {
+        let mut usb_handle = open_mouse()?;
+        apply_charging_effect(&mut usb_handle, state, &effect)?;
+    }
+    let msg = format!("{:?} effect successfully set to {}", state, effect.name());
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| {
+        s.charging_effects.insert(state, effect);
+    })
+}
+
+#[tauri::command]
+pub fn set_device_custom_frame(app: AppHandle, frame: Vec<Vec<[u8; 3]>>) -> Result<(), String> {
+    set_device_lighting_effect(app, LightingEffect::CustomFrame { frame })
+}
+
+#[tauri::command]
+pub fn set_dock_lighting_effect(app: AppHandle, effect: LightingEffect) -> Result<(), String> {
+    if let LightingEffect::CustomFrame { .. } = effect {
+        return Err("The Mouse Dock Pro has no LED matrix for custom frames".to_string());
+    }
+    unsafe {
+        let mut usb_handle = open_dock()?;
+        apply_lighting_effect(&mut usb_handle, &effect)?;
+    }
+    let msg = format!("Dock lighting effect successfully set to {}", effect.name());
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.dock_lighting_effect = Some(effect))
+}
+
+#[tauri::command]
+pub fn set_dock_brightness(app: AppHandle, brightness: u8) -> Result<(), String> {
+    unsafe {
+        with_dock(|d| d.set_led_brightness(ZERO_LED, brightness))?;
+    }
+    let msg = format!("Dock brightness successfully set to {}%", brightness);
+    log::info!("{}", msg);
+    println!("{}", msg);
+    update_settings(app, |s| s.dock_brightness = Some(brightness))
+}
+
+#[tauri::command]
+pub fn set_argb_channel_colors(channel: u8, colors: Vec<[u8; 3]>) -> Result<(), String> {
+    unsafe {
+        let descriptor = find_connected_device(DeviceKind::ArgbController)
+            .ok_or_else(|| "No Razer ARGB controller connected".to_string())?;
+        let mut usb_handle = RazerDevice::open(descriptor).map_err(|e| e.to_string())?;
+        usb_handle.send_argb_frame(channel, &colors).map_err(|e| e.to_string())
     }
-    update_settings(app, |s| s.rgb_color = [r, g, b])
 }
 
 #[tauri::command]
 pub fn get_device_led_rgb() -> Result<[u8; 3], String> {
-    unsafe { get_led_rgb() }
+    unsafe { execute_on_mouse(GetLedRgb { led_id: BACKLIGHT_LED }) }.map(|led| led.rgb)
 }
 
 #[tauri::command]
 pub fn get_device_dpi_stages() -> Result<Vec<DpiStage>, String> {
-    unsafe { get_dpi_stages() }
+    unsafe { execute_on_mouse(GetDpiStages) }.map(dpi_stages_from_device)
 }
 
 #[tauri::command]
 pub fn set_device_dpi_stages(app: AppHandle, stages: Vec<DpiStage>) -> Result<(), String> {
     unsafe {
-        set_dpi_stages(stages.clone())?;
+        execute_on_mouse(set_dpi_stages_command(&stages)?)?;
     }
+    let active_stage = stages.iter().find(|s| s.active).map(|s| s.stage).unwrap_or(1);
+    let msg = format!("DPI stages successfully updated (Active Stage: {})", active_stage);
+    log::info!("{}", msg);
+    println!("{}", msg);
     update_settings(app, |s| s.dpi_stages = stages)
 }
 
@@ -286,8 +497,7 @@ pub unsafe fn apply_saved_settings(settings: &MouseSettings) {
     info!("Applying saved settings to device: {:?}", settings);
     println!("Applying saved settings to device: {:?}", settings);
 
-    let mut usb_handle = match PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID)
-    {
+    let mut usb_handle = match open_mouse() {
         Ok(h) => h,
         Err(e) => {
             error!("Failed to open device for applying settings: {}", e);
@@ -295,11 +505,47 @@ pub unsafe fn apply_saved_settings(settings: &MouseSettings) {
         }
     };
 
-    let _ = set_dpi_xy_with_handle(&mut usb_handle, settings.dpi_x, settings.dpi_y);
-    let _ = set_dpi_stages_with_handle(&mut usb_handle, settings.dpi_stages.clone());
-    let _ = set_polling_rate_with_handle(&mut usb_handle, settings.polling_rate);
-    let _ = set_matrix_backlight_static_with_handle(&mut usb_handle, settings.rgb_color);
-    let _ = set_backlight_with_handle(&mut usb_handle, settings.brightness);
+    apply_setting(&mut usb_handle, "DPI", SetDpiXy::new(settings.dpi_x, settings.dpi_y).map_err(String::from));
+    apply_setting(&mut usb_handle, "DPI stages", set_dpi_stages_command(&settings.dpi_stages));
+    let polling_result = match find_connected_device(DeviceKind::Receiver) {
+        Some(receiver) => RazerDevice::open(receiver).and_then(|mut r| r.set_polling_rate(settings.polling_rate)),
+        None => usb_handle.set_polling_rate(settings.polling_rate),
+    };
+    if let Err(e) = polling_result {
+        error!("Failed to apply polling rate: {}", e);
+    }
+    if let Err(e) = usb_handle.set_smart_reel(settings.smart_wheel_enabled) {
+        error!("Failed to apply smart wheel: {}", e);
+    }
+    if let Err(e) = usb_handle.set_scroll_acceleration(settings.scroll_acceleration) {
+        error!("Failed to apply scroll acceleration: {}", e);
+    }
+    if let Err(e) = usb_handle.set_scroll_mode(settings.scroll_mode) {
+        error!("Failed to apply scroll mode: {}", e);
+    }
+    if let Some(seconds) = settings.idle_timeout_secs {
+        if let Err(e) = usb_handle.set_idle_timeout(seconds) {
+            error!("Failed to apply idle timeout: {}", e);
+        }
+    }
+    if let Some(percentage) = settings.low_battery_threshold {
+        if let Err(e) = usb_handle.set_low_battery_threshold(percentage) {
+            error!("Failed to apply low battery threshold: {}", e);
+        }
+    }
+    if let Err(e) = apply_lighting_effect(&mut usb_handle, &settings.current_lighting_effect()) {
+        error!("Failed to apply lighting effect: {}", e);
+    }
+    for (zone, brightness) in &settings.brightness {
+        if let Err(e) = usb_handle.set_led_brightness(zone.led_id(), *brightness) {
+            error!("Failed to apply {:?} brightness: {}", zone, e);
+        }
+    }
+    for (state, effect) in &settings.charging_effects {
+        if let Err(e) = apply_charging_effect(&mut usb_handle, *state, effect) {
+            error!("Failed to apply {:?} effect: {}", state, e);
+        }
+    }
 
     drop(usb_handle);
 
@@ -308,6 +554,41 @@ pub unsafe fn apply_saved_settings(settings: &MouseSettings) {
         .map_err(|e| e.to_string());
 }
 
+/// Applies the saved dock lighting, the dock connects independently of the mouse.
+pub unsafe fn apply_saved_dock_settings(settings: &MouseSettings) {
+    let mut usb_handle = match open_dock() {
+        Ok(h) => h,
+        Err(e) => {
+            info!("Skipping dock settings: {}", e);
+            return;
+        }
+    };
+
+    if let Some(effect) = &settings.dock_lighting_effect {
+        if let Err(e) = apply_lighting_effect(&mut usb_handle, effect) {
+            error!("Failed to apply dock lighting effect: {}", e);
+        }
+    }
+    if let Some(brightness) = settings.dock_brightness {
+        if let Err(e) = usb_handle.set_led_brightness(ZERO_LED, brightness) {
+            error!("Failed to apply dock brightness: {}", e);
+        }
+    }
+
+    drop(usb_handle);
+}
+
+unsafe fn apply_setting<C: Command<Response = ()>>(
+    usb_handle: &mut Mouse,
+    name: &str,
+    command: Result<C, String>,
+) {
+    let res = command.and_then(|command| usb_handle.execute(command).map_err(|e| e.to_string()));
+    if let Err(e) = res {
+        error!("Failed to apply {}: {}", name, e);
+    }
+}
+
 pub unsafe fn apply_default_settings() {
     info!("Applying default settings...");
     println!("Applying default settings...");
@@ -11,15 +11,24 @@ struct IsQuitting(AtomicBool);
 use driver::{PlatformUsbDriver, PreferencesDriver, UsbDriver};
 use tauri_plugin_updater::UpdaterExt;
 use handler::{
-    apply_saved_settings, get_device_backlight_brightness, get_device_battery_status,
+    apply_saved_dock_settings, apply_saved_settings, get_device_backlight_brightness, get_device_battery_status,
     get_device_charging_status, get_device_dpi_stages, get_device_information,
-    get_device_led_rgb, get_saved_settings,
-    get_target_os, save_settings, set_device_backlight_brightness, set_device_dpi,
-    set_device_dpi_stages, set_device_matrix_backlight_static, set_device_polling_rate,
-//    set_device_smart_wheel, 
+    get_device_led_brightness, get_device_led_rgb, get_saved_settings,
+    get_target_os, save_settings, set_argb_channel_colors, set_device_backlight_brightness,
+    set_device_dpi,
+    set_device_custom_frame, set_device_dpi_stages, set_device_led_brightness,
+    set_device_lighting_effect, set_device_charging_effect,
+    set_device_matrix_backlight_static,
+    get_device_free_spin, get_device_idle_timeout, get_device_low_battery_threshold,
+    get_device_scroll_acceleration, get_device_smart_wheel, set_device_free_spin,
+    set_device_idle_timeout, set_device_low_battery_threshold,
+    set_device_polling_rate,
+    set_device_scroll_acceleration,
+    set_device_smart_wheel,
+    set_dock_brightness, set_dock_lighting_effect,
     set_mouse_wheel_inverted,
 };
-use razer::{RAZER_BASILISK_V3_PRO_ID, RAZER_USB_VENDOR_ID};
+use razer::{DeviceKind, RAZER_USB_VENDOR_ID, SUPPORTED_DEVICES};
 use types::{DeviceCollection, DeviceInfo};
 
 pub struct Application {
@@ -57,6 +66,18 @@ impl Application {
     }
 }
 
+/// Tray label with firmware version and serial number of the connected mouse.
+fn device_details_text() -> String {
+    match unsafe { mouse::connected_mouse_details() } {
+        Some(device) => format!(
+            "Firmware: {} - Serial: {}",
+            device.firmware_version.map(|v| v.to_string()).unwrap_or_else(|| "--".into()),
+            device.serial_number.unwrap_or_else(|| "--".into())
+        ),
+        None => "Firmware: --".to_string(),
+    }
+}
+
 pub fn create_app() -> Application {
     Application::new(
         tauri::Builder::default()
@@ -73,14 +94,31 @@ pub fn create_app() -> Application {
             .invoke_handler(tauri::generate_handler![
                 get_device_information,
                 set_device_matrix_backlight_static,
+                set_device_lighting_effect,
+                set_device_charging_effect,
+                set_device_custom_frame,
                 set_device_backlight_brightness,
                 get_device_backlight_brightness,
+                set_device_led_brightness,
+                get_device_led_brightness,
                 set_device_polling_rate,
                 set_device_dpi,
                 get_device_led_rgb,
+                set_argb_channel_colors,
+                set_dock_lighting_effect,
+                set_dock_brightness,
                 get_target_os,
                 set_mouse_wheel_inverted,
-//                set_device_smart_wheel,
+                set_device_smart_wheel,
+                get_device_smart_wheel,
+                set_device_scroll_acceleration,
+                get_device_scroll_acceleration,
+                set_device_free_spin,
+                get_device_free_spin,
+                set_device_idle_timeout,
+                get_device_idle_timeout,
+                set_device_low_battery_threshold,
+                get_device_low_battery_threshold,
                 get_device_dpi_stages,
                 set_device_dpi_stages,
                 get_device_battery_status,
@@ -183,6 +221,11 @@ pub fn create_app() -> Application {
                             }
                         }
                     }
+                    if let Ok(settings) = get_saved_settings(handle.clone()) {
+                        unsafe {
+                            apply_saved_dock_settings(&settings);
+                        }
+                    }
 
                     println!("Emitting loading-status: Initializing...");
                     let _ = handle.emit_to("splashscreen", "loading-status", "Initializing...");
@@ -211,6 +254,13 @@ pub fn create_app() -> Application {
                     true,
                     None::<&str>,
                 )?;
+                let device_details = tauri::menu::MenuItem::with_id(
+                    app,
+                    "device_details",
+                    "Firmware: --",
+                    true,
+                    None::<&str>,
+                )?;
                 let check_updates = tauri::menu::MenuItem::with_id(
                     app,
                     "check_updates",
@@ -225,6 +275,7 @@ pub fn create_app() -> Application {
                     app,
                     &[
                         &battery_status,
+                        &device_details,
                         &separator,
                         &open_ui,
                         &settings,
@@ -329,6 +380,9 @@ pub fn create_app() -> Application {
                             });
                         }
                         "quit" => {
+                            if let Err(e) = unsafe { mouse::restore_normal_mode() } {
+                                log::error!("Failed to restore normal device mode: {}", e);
+                            }
                             app.state::<IsQuitting>().0.store(true, Ordering::SeqCst);
                             app.exit(0);
                         }
@@ -337,10 +391,13 @@ pub fn create_app() -> Application {
                     .build(app)?;
 
                 let battery_status_c = battery_status.clone();
+                let device_details_c = device_details.clone();
                 _tray.on_tray_icon_event(move |_tray_handle, event| {
                     if let tauri::tray::TrayIconEvent::Click { .. } = event {
                         let battery_status_item = battery_status_c.clone();
+                        let device_details_item = device_details_c.clone();
                         tauri::async_runtime::spawn(async move {
+                            let _ = device_details_item.set_text(device_details_text());
                             match get_device_battery_status() {
                                 Ok(level) => {
                                     let _ = battery_status_item.set_text(format!("Battery: {}%", level));
@@ -355,7 +412,9 @@ pub fn create_app() -> Application {
 
                 // Initial battery status update
                 let battery_status_c = battery_status.clone();
+                let device_details_c = device_details.clone();
                 tauri::async_runtime::spawn(async move {
+                    let _ = device_details_c.set_text(device_details_text());
                     match get_device_battery_status() {
                         Ok(level) => {
                             let _ = battery_status_c.set_text(format!("Battery: {}%", level));
@@ -366,38 +425,67 @@ pub fn create_app() -> Application {
                     }
                 });
 
-                let app_handle = app.handle().clone();
-                PlatformUsbDriver::on_device_connected(
-                    RAZER_USB_VENDOR_ID,
-                    RAZER_BASILISK_V3_PRO_ID,
-                    move |_device| {
-                        log::info!("USB dongle connected - applying saved settings");
-                        println!("USB dongle connected - applying saved settings");
-                        let handle = app_handle.clone();
-                        tauri::async_runtime::spawn(async move {
-                            if let Ok(settings) = get_saved_settings(handle) {
-                                unsafe {
-                                    apply_saved_settings(&settings);
+                for descriptor in SUPPORTED_DEVICES.iter().filter(|d| d.kind == DeviceKind::Mouse) {
+                    let app_handle = app.handle().clone();
+                    PlatformUsbDriver::on_device_connected(
+                        RAZER_USB_VENDOR_ID,
+                        descriptor.product_id,
+                        move |_device| {
+                            log::info!("USB dongle connected - applying saved settings");
+                            println!("USB dongle connected - applying saved settings");
+                            let handle = app_handle.clone();
+                            tauri::async_runtime::spawn(async move {
+                                if let Ok(settings) = get_saved_settings(handle) {
+                                    unsafe {
+                                        apply_saved_settings(&settings);
+                                    }
                                 }
+                            });
+                        },
+                    )
+                    .map_err(|e| e.to_string())
+                    .expect("Failed to register connection hook");
+
+                    PlatformUsbDriver::on_device_disconnected(
+                        RAZER_USB_VENDOR_ID,
+                        descriptor.product_id,
+                        |_device| {
+                            log::info!("USB dongle disconnected - reverting trackpad settings");
+                            println!("USB dongle disconnected - reverting trackpad settings");
+                            let _ =
+                                driver::PlatformPreferencesDriver::set_mouse_wheel_inverted(true);
+                            // Unplugging the cable of a mouse that is also paired to a dongle
+                            // leaves it reachable through the other product id.
+                            if unsafe { mouse::restore_normal_mode() }.is_ok() {
+                                log::info!("Restored normal device mode");
                             }
-                        });
-                    },
-                )
-                .map_err(|e| e.to_string())
-                .expect("Failed to register connection hook");
-
-                PlatformUsbDriver::on_device_disconnected(
-                    RAZER_USB_VENDOR_ID,
-                    RAZER_BASILISK_V3_PRO_ID,
-                    |_device| {
-                        log::info!("USB dongle disconnected - reverting trackpad settings");
-                        println!("USB dongle disconnected - reverting trackpad settings");
-                        let _ =
-                            driver::PlatformPreferencesDriver::set_mouse_wheel_inverted(true);
-                    },
-                )
-                .map_err(|e| e.to_string())
-                .expect("Failed to register disconnection hook");
+                        },
+                    )
+                    .map_err(|e| e.to_string())
+                    .expect("Failed to register disconnection hook");
+                }
+
+                for descriptor in SUPPORTED_DEVICES.iter().filter(|d| d.kind == DeviceKind::Dock) {
+                    let app_handle = app.handle().clone();
+                    PlatformUsbDriver::on_device_connected(
+                        RAZER_USB_VENDOR_ID,
+                        descriptor.product_id,
+                        move |_device| {
+                            log::info!("Mouse dock connected - applying saved lighting");
+                            println!("Mouse dock connected - applying saved lighting");
+                            let handle = app_handle.clone();
+                            tauri::async_runtime::spawn(async move {
+                                if let Ok(settings) = get_saved_settings(handle) {
+                                    unsafe {
+                                        apply_saved_dock_settings(&settings);
+                                    }
+                                }
+                            });
+                        },
+                    )
+                    .map_err(|e| e.to_string())
+                    .expect("Failed to register dock connection hook");
+                }
 
                 // Start polling thread to detect wireless mouse power state changes
                 let app_handle = app.handle().clone();
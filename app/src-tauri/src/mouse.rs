@@ -1,28 +1,84 @@
 use crate::types::DpiStage;
-use driver::UsbDriver;
-use razer::DpiStage as RazerDpiStage;
+use driver::settings::{ChargingLed, LightingEffect};
+use driver::{identify, Device, DriverResult, PlatformUsbDriver, RazerDevice, UsbDriver};
 use razer::{
-    RazerReport, BACKLIGHT_LED, RAZER_BASILISK_V3_PRO_ID, RAZER_NEW_MOUSE_RECEIVER_WAIT_MAX_US,
-    RAZER_USB_REPORT_LEN, RAZER_USB_VENDOR_ID, ZERO_LED,
+    Command, CommandStatus, DeviceDescriptor, DeviceKind, DeviceMode, DpiStages,
+    GetFirmwareVersion, SetCustomFrame, SetDpiStages, RAZER_USB_VENDOR_ID, ZERO_LED,
 };
-use std::time::Duration;
 
-unsafe fn get_data_for_razer_report(
-    usb_handle: &mut driver::PlatformUsbDriver,
-    index: u16,
-    razer_report: &mut RazerReport,
-) -> Result<Vec<u8>, String> {
-    razer_report.finalize();
-    let report_data = razer_report.to_hid_bytes();
+pub type Mouse = RazerDevice<PlatformUsbDriver>;
 
-    usb_handle
-        .get_feature_report(
-            report_data.as_slice(),
-            index,
-            Duration::from_micros(RAZER_NEW_MOUSE_RECEIVER_WAIT_MAX_US as u64),
-            RAZER_USB_REPORT_LEN as u16,
-        )
-        .map_err(|e| e.to_string())
+/// Returns the descriptor of the first connected Razer device of `kind` we know how to talk to.
+pub unsafe fn find_connected_device(kind: DeviceKind) -> Option<&'static DeviceDescriptor> {
+    PlatformUsbDriver::list_devices()
+        .iter()
+        .filter(|dev| dev.vendor_id == RAZER_USB_VENDOR_ID as u32)
+        .filter_map(|dev| razer::find_device(dev.product_id as u16))
+        .find(|descriptor| descriptor.kind == kind)
+}
+
+pub unsafe fn find_connected_mouse() -> Option<&'static DeviceDescriptor> {
+    find_connected_device(DeviceKind::Mouse)
+}
+
+/// The connected mouse with its serial number and firmware version, asked only for this device.
+pub unsafe fn connected_mouse_details() -> Option<Device> {
+    let descriptor = find_connected_mouse()?;
+    let mut device = PlatformUsbDriver::list_devices()
+        .into_iter()
+        .find(|dev| dev.product_id == descriptor.product_id as u32)?;
+    identify::<PlatformUsbDriver>(&mut device);
+    Some(device)
+}
+
+pub unsafe fn open_mouse() -> Result<Mouse, String> {
+    let descriptor =
+        find_connected_mouse().ok_or_else(|| "No supported Razer mouse connected".to_string())?;
+    RazerDevice::open(descriptor).map_err(|e| e.to_string())
+}
+
+/// Opens the mouse, runs `f` on it and closes the handle again.
+pub unsafe fn with_mouse<R>(f: impl FnOnce(&mut Mouse) -> DriverResult<R>) -> Result<R, String> {
+    let mut usb_handle = open_mouse()?;
+    let res = f(&mut usb_handle).map_err(|e| e.to_string());
+    drop(usb_handle);
+    res
+}
+
+/// Opens the mouse, executes a single command and closes the handle again.
+pub unsafe fn execute_on_mouse<C: Command>(command: C) -> Result<C::Response, String> {
+    with_mouse(|usb_handle| usb_handle.execute(command))
+}
+
+pub unsafe fn open_dock() -> Result<RazerDevice<PlatformUsbDriver>, String> {
+    let descriptor = find_connected_device(DeviceKind::Dock)
+        .ok_or_else(|| "No Razer Mouse Dock Pro connected".to_string())?;
+    RazerDevice::open(descriptor).map_err(|e| e.to_string())
+}
+
+/// The connected HyperPolling receiver, falling back to the mouse. A mouse paired with a
+/// receiver takes its polling rate from the receiver, all other settings go to the mouse.
+pub unsafe fn find_polling_device() -> Option<&'static DeviceDescriptor> {
+    find_connected_device(DeviceKind::Receiver).or_else(|| find_connected_mouse())
+}
+
+/// Opens the dock, runs `f` on it and closes the handle again.
+pub unsafe fn with_dock<R>(f: impl FnOnce(&mut RazerDevice<PlatformUsbDriver>) -> DriverResult<R>) -> Result<R, String> {
+    let mut usb_handle = open_dock()?;
+    let res = f(&mut usb_handle).map_err(|e| e.to_string());
+    drop(usb_handle);
+    res
+}
+
+/// Hands buttons and lighting back to the firmware, so the mouse keeps working
+/// without the app when it was left in driver mode.
+pub unsafe fn restore_normal_mode() -> Result<(), String> {
+    with_mouse(|usb_handle| {
+        if usb_handle.device_mode()? != DeviceMode::Normal {
+            usb_handle.set_device_mode(DeviceMode::Normal)?;
+        }
+        Ok(())
+    })
 }
 
 /// Lightweight check to see if the mouse is responsive.
@@ -36,15 +92,15 @@ unsafe fn get_data_for_razer_report(
 /// 1. Wireless mouse power state (on/off while dongle stays plugged in)
 /// 2. Physical dongle unplug (device not found)
 pub unsafe fn is_mouse_alive() -> bool {
-    match driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID) {
+    match open_mouse() {
         Ok(mut usb_handle) => {
-            let mut firmware_report = RazerReport::get_firmware_report();
-            match get_data_for_razer_report(&mut usb_handle, 0x00, &mut firmware_report) {
-                Ok(data) => {
+            let mut firmware_report = GetFirmwareVersion.report();
+            match usb_handle.send_report(&mut firmware_report) {
+                Ok(response) => {
                     // Status byte meanings:
                     // 0x02 = Command Successful (mouse is ON)
                     // 0x04 = Command No Response / Timeout (mouse is OFF)
-                    let is_alive = data[0] == 0x02;
+                    let is_alive = response.status() == CommandStatus::Successful;
 
                     drop(usb_handle);
                     is_alive
@@ -59,336 +115,55 @@ pub unsafe fn is_mouse_alive() -> bool {
     }
 }
 
-pub unsafe fn is_mouse_charging() -> Result<bool, String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = is_mouse_charging_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn is_mouse_charging_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<bool, String> {
-    let mut get_charging_report = RazerReport::get_charging_state_report();
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_charging_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
-    // 0x00 or 0x01 on args[1]
-    Ok(report.arguments[1] == 0x01)
-}
-
-pub unsafe fn get_battery_status() -> Result<u8, String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = get_battery_status_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn get_battery_status_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<u8, String> {
-    let mut get_battery_report = RazerReport::get_battery_level_report();
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_battery_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
-    let raw_battery_status = report.arguments[1];
-    Ok((raw_battery_status as f32 / 255f32 * 100f32) as u8)
-}
-
-pub unsafe fn get_polling_rate() -> Result<u16, String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = get_polling_rate_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn get_polling_rate_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<u16, String> {
-    let mut get_poll_rate_report = RazerReport::get_poll_rate_report();
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_poll_rate_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
-    match report.arguments[0] {
-        0x01 => Ok(1000),
-        0x02 => Ok(500),
-        0x08 => Ok(125),
-        _ => Err(format!("Unknown polling rate: {}", report.arguments[0])),
-    }
-}
-
-pub unsafe fn set_backlight(brightness: u8) -> Result<(), String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = set_backlight_with_handle(&mut usb_handle, brightness);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn set_backlight_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-    brightness: u8,
-) -> Result<(), String> {
-    let mut set_brightness_report = RazerReport::set_matrix_brightness_report(brightness);
-    get_data_for_razer_report(usb_handle, 0x00, &mut set_brightness_report)?;
-    let msg = format!("Backlight brightness successfully set to {}%", brightness);
-    log::info!("{}", msg);
-    println!("{}", msg);
-    Ok(())
-}
-
-pub unsafe fn get_backlight() -> Result<u8, String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = get_backlight_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn get_backlight_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<u8, String> {
-    let mut get_brightness_report = RazerReport::get_matrix_brightness_report();
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_brightness_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
-    Ok(report.arguments[0])
-}
-
-pub unsafe fn set_polling_rate(polling_rate: u16) -> Result<(), String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = set_polling_rate_with_handle(&mut usb_handle, polling_rate);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn set_polling_rate_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-    polling_rate: u16,
-) -> Result<(), String> {
-    let mut set_poll_rate_report = RazerReport::set_poll_rate_report(polling_rate)?;
-    get_data_for_razer_report(usb_handle, 0x00, &mut set_poll_rate_report)?;
-    let msg = format!("Polling rate successfully set to {}Hz", polling_rate);
-    log::info!("{}", msg);
-    println!("{}", msg);
-    Ok(())
-}
-
-pub unsafe fn get_dpi_xy() -> Result<(u16, u16), String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = get_dpi_xy_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn get_dpi_xy_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<(u16, u16), String> {
-    let mut get_dpi_report = RazerReport::get_dpi_xy_report();
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_dpi_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
-    let dpi_x = ((report.arguments[1] as u16) << 8) | (report.arguments[2] as u16 & 0xFF);
-    let dpi_y = ((report.arguments[3] as u16) << 8) | (report.arguments[4] as u16 & 0xFF);
-    Ok((dpi_x, dpi_y))
-}
-
-pub unsafe fn set_dpi_xy(dpi_x: u16, dpi_y: u16) -> Result<(), String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = set_dpi_xy_with_handle(&mut usb_handle, dpi_x, dpi_y);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn set_dpi_xy_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-    dpi_x: u16,
-    dpi_y: u16,
-) -> Result<(), String> {
-    let mut set_dpi_report = RazerReport::set_dpi_xy_report(dpi_x, dpi_y);
-    get_data_for_razer_report(usb_handle, 0x00, &mut set_dpi_report)?;
-    let msg = format!("DPI successfully set to {}x{}", dpi_x, dpi_y);
-    log::info!("{}", msg);
-    println!("{}", msg);
-    Ok(())
-}
-
-pub unsafe fn set_matrix_backlight_static(rgb: [u8; 3]) -> Result<(), String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = set_matrix_backlight_static_with_handle(&mut usb_handle, rgb);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn set_matrix_backlight_static_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-    rgb: [u8; 3],
-) -> Result<(), String> {
-    let mut set_static_report = RazerReport::set_matrix_effect_static_report(rgb, Some(ZERO_LED));
-    get_data_for_razer_report(usb_handle, 0x00, &mut set_static_report)?;
-    let msg = format!(
-        "Matrix backlight successfully set to static RGB: [{}, {}, {}]",
-        rgb[0], rgb[1], rgb[2]
-    );
-    log::info!("{}", msg);
-    println!("{}", msg);
-    Ok(())
-}
-
-pub unsafe fn get_led_rgb() -> Result<[u8; 3], String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = get_led_rgb_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
-}
-
-pub unsafe fn get_led_rgb_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<[u8; 3], String> {
-    let mut get_led_report = RazerReport::get_led_rgb_report(Some(BACKLIGHT_LED));
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_led_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
-
-    if report.arguments.len() < 3 {
-        return Err("Invalid LED RGB data received".to_string());
-    }
-
-    Ok([
-        report.arguments[0],
-        report.arguments[1],
-        report.arguments[2],
-    ])
+pub fn dpi_stages_from_device(dpi_stages: DpiStages) -> Vec<DpiStage> {
+    dpi_stages
+        .stages
+        .into_iter()
+        .map(|stage| DpiStage {
+            active: stage.stage == dpi_stages.active_stage,
+            stage: stage.stage,
+            dpi_x: stage.dpi_x,
+            dpi_y: stage.dpi_y,
+        })
+        .collect()
 }
 
-pub unsafe fn get_dpi_stages() -> Result<Vec<DpiStage>, String> {
-    /*
-    // Response format (hex):
-    // 01    varstore
-    // 02    active DPI stage
-    // 04    number of stages = 4
-    //
-    // 01    first DPI stage
-    // 03 20 first stage DPI X = 800
-    // 03 20 first stage DPI Y = 800
-    // 00 00 reserved
-    //
-    // 02    second DPI stage
-    // 07 08 second stage DPI X = 1800
-    // 07 08 second stage DPI Y = 1800
-    // 00 00 reserved
-    //
-    // 03    third DPI stage
-    // ...
-
-        stages_count = response.arguments[2];
-
-        buf[0] = response.arguments[1];
-
-        count = 1;
-        args = response.arguments + 4;
-        for (i = 0; i < stages_count; i++) {
-            // Check that we don't read past response.data_size
-            if (args + 4 > response.arguments + response.data_size) {
-                break;
-            }
-
-            memcpy(buf + count, args, 4);
-            count += 4;
-            args += 7;
-        }
-
-        return count;
-     */
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = get_dpi_stages_with_handle(&mut usb_handle);
-    drop(usb_handle);
-    res
+pub fn set_dpi_stages_command(stages: &[DpiStage]) -> Result<SetDpiStages, String> {
+    Ok(driver::settings::dpi_stages_command(stages)?)
 }
-pub unsafe fn get_dpi_stages_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-) -> Result<Vec<DpiStage>, String> {
-    let mut get_dpi_stages_report = RazerReport::get_dpi_stages_report();
-    let data = get_data_for_razer_report(usb_handle, 0x00, &mut get_dpi_stages_report)?;
-    let report = RazerReport::from_bytes(data.as_slice());
 
-    if report.arguments.len() < 3 {
-        return Err("Invalid DPI stages data received".to_string());
-    }
-
-    let mut data: Vec<DpiStage> = vec![];
-    let mut args = &report.arguments[4..];
-    let active_stage = report.arguments[1];
-    let dpi_stages_count = report.arguments[2];
-
-    for current_stage in 0..dpi_stages_count {
-        if args.len() < 4 {
-            break;
+/// Applies a lighting effect to the mouse or dock, uploading the frame first for custom frames.
+pub unsafe fn apply_lighting_effect(usb_handle: &mut RazerDevice<PlatformUsbDriver>, effect: &LightingEffect) -> Result<(), String> {
+    if let LightingEffect::CustomFrame { frame } = effect {
+        let descriptor = usb_handle.descriptor();
+        if frame.len() > descriptor.matrix_rows as usize
+            || frame.iter().any(|row| row.len() > descriptor.matrix_columns as usize)
+        {
+            return Err(format!(
+                "Custom frame doesn't fit the {}x{} LED matrix of the {}",
+                descriptor.matrix_rows, descriptor.matrix_columns, descriptor.name
+            ));
         }
 
-        let dpi_x = ((args[0] as u16) << 8) | (args[1] as u16 & 0xFF);
-        let dpi_y = ((args[2] as u16) << 8) | (args[3] as u16 & 0xFF);
-        let active = (current_stage + 1) == active_stage;
-
-        data.push(DpiStage {
-            stage: current_stage + 1,
-            dpi_x,
-            dpi_y,
-            active,
-        });
-
-        if args.len() >= 7 {
-            args = &args[7..];
-        } else {
-            break;
+        for (row, rgb) in frame.iter().enumerate().filter(|(_, rgb)| !rgb.is_empty()) {
+            usb_handle
+                .execute(SetCustomFrame::new(row as u8, 0, rgb.clone())?)
+                .map_err(|e| e.to_string())?;
         }
     }
 
-    Ok(data)
-}
-
-pub unsafe fn set_dpi_stages(stages: Vec<DpiStage>) -> Result<(), String> {
-    let mut usb_handle =
-        driver::PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, RAZER_BASILISK_V3_PRO_ID).map_err(|e| e.to_string())?;
-    let res = set_dpi_stages_with_handle(&mut usb_handle, stages);
-    drop(usb_handle);
-    res
+    usb_handle
+        .set_lighting_effect(ZERO_LED, effect.matrix_effect()?)
+        .map_err(|e| e.to_string())
 }
 
-pub unsafe fn set_dpi_stages_with_handle(
-    usb_handle: &mut driver::PlatformUsbDriver,
-    stages: Vec<DpiStage>,
-) -> Result<(), String> {
-    if stages.is_empty() {
-        return Err("No DPI stages provided".to_string());
+/// Sets the effect the mouse shows in a charging state, frames can't be shown there.
+pub unsafe fn apply_charging_effect(usb_handle: &mut Mouse, led: ChargingLed, effect: &LightingEffect) -> Result<(), String> {
+    if let LightingEffect::CustomFrame { .. } = effect {
+        return Err(format!("Custom frames can't be shown while {:?}", led));
     }
 
-    let active_dpi_stage = stages
-        .iter()
-        .find(|s| s.active)
-        .map(|s| s.stage)
-        .unwrap_or(1);
-    let dpi_stages: Vec<RazerDpiStage> = stages
-        .iter()
-        .map(|dpi_stage| RazerDpiStage {
-            stage: dpi_stage.stage,
-            dpi_x: dpi_stage.dpi_x,
-            dpi_y: dpi_stage.dpi_y,
-        })
-        .collect();
-
-    let mut set_dpi_stages_report =
-        RazerReport::set_dpi_stages_report(active_dpi_stage, dpi_stages);
-    get_data_for_razer_report(usb_handle, 0x00, &mut set_dpi_stages_report)?;
-
-    let msg = format!(
-        "DPI stages successfully updated (Active Stage: {})",
-        active_dpi_stage
-    );
-    log::info!("{}", msg);
-    println!("{}", msg);
-    Ok(())
+    usb_handle
+        .set_lighting_effect(led.led_id(), effect.matrix_effect()?)
+        .map_err(|e| e.to_string())
 }
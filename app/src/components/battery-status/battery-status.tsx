@@ -1,6 +1,8 @@
 export type BatteryStatusComponentProps = {
     batteryLevel: number;
     isCharging: boolean;
+    /** Whether the dock reports charging, null when no dock is connected. */
+    dockCharging: boolean | null;
 }
 
 export const BatteryStatus = (props: BatteryStatusComponentProps) => {
@@ -17,6 +19,14 @@ export const BatteryStatus = (props: BatteryStatusComponentProps) => {
                     </svg>
                 )}
             </div>
+            {props.dockCharging !== null && (
+                <span
+                    className={`mr-2 text-xs font-bold select-none ${props.dockCharging ? "text-white" : "text-white/40"}`}
+                    title={props.dockCharging ? "Dock is charging" : "Dock is not charging"}
+                >
+                    DOCK
+                </span>
+            )}
             <div className="flex items-center justify-center border-2 border-white rounded-md px-4 py-0.5 bg-gray-800/40 backdrop-blur-md min-w-[4.2rem] h-[24px] shadow-lg">
                 <span className="font-bold text-white text-base tracking-tight select-none">
                     {props.batteryLevel ? `${props.batteryLevel}%` : "--%"}
@@ -16,7 +16,7 @@ export const useDeviceManager = () => {
         setDpiXy,
         setPollingRate,
         setMouseWheelInverted,
-        //         setSmartWheelEnabled,
+        setSmartWheelEnabled,
         getDpiStages,
         setDpiStages,
         appSettings,
@@ -33,7 +33,7 @@ export const useDeviceManager = () => {
         setDpiXy,
         setPollingRate,
         setMouseWheelInverted,
-        //         setSmartWheelEnabled,
+        setSmartWheelEnabled,
         getDpiStages,
         setDpiStages,
         appSettings,
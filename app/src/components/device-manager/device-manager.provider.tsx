@@ -38,8 +38,9 @@ function DeviceManagerProviderComponent(props: PropsWithChildren<DeviceManagerPr
     }, []);
 
     const setPollingRate = useCallback((pollingRate: number) => {
-        if (![125, 250, 500, 1000].includes(pollingRate)) {
-            throw new Error("Invalid polling rate. Must be one of: 125, 250, 500, 1000");
+        const supportedPollingRates: number[] = deviceInformation?.supportedPollingRates ?? [];
+        if (!supportedPollingRates.includes(pollingRate)) {
+            throw new Error(`Invalid polling rate. Must be one of: ${supportedPollingRates.join(", ")}`);
         }
 
         console.log("pollingRate: ", pollingRate)
@@ -49,7 +50,7 @@ function DeviceManagerProviderComponent(props: PropsWithChildren<DeviceManagerPr
                 setDeviceInformation(prev => prev ? { ...prev, pollingRate: pollingRate as PossiblePollingRates } : null);
             })
             .catch(handleError);
-    }, [api]);
+    }, [api, deviceInformation?.supportedPollingRates]);
 
     const setDpiXy = useCallback((dpiX: number, dpiY: number) => {
         return api.setDpiXy(dpiX, dpiY)
@@ -75,13 +76,13 @@ function DeviceManagerProviderComponent(props: PropsWithChildren<DeviceManagerPr
             .catch(handleError);
     }, [api]);
 
-    //     const setSmartWheelEnabled = useCallback((enabled: boolean) => {
-    //         return api.setSmartWheelEnabled(enabled)
-    //             .then(() => {
-    //                 setDeviceInformation(prev => prev ? { ...prev, smartWheelEnabled: enabled } : null);
-    //             })
-    //             .catch(handleError);
-    //     }, [api]);
+    const setSmartWheelEnabled = useCallback((enabled: boolean) => {
+        return api.setSmartWheelEnabled(enabled)
+            .then(() => {
+                setDeviceInformation(prev => prev ? { ...prev, smartWheelEnabled: enabled } : null);
+            })
+            .catch(handleError);
+    }, [api]);
 
     const setMouseWheelInverted = useCallback((inverted: boolean) => {
         return api.setMouseWheelInverted(inverted)
@@ -180,7 +181,7 @@ function DeviceManagerProviderComponent(props: PropsWithChildren<DeviceManagerPr
         setDpiXy,
         setPollingRate,
         setMouseWheelInverted,
-        //         setSmartWheelEnabled,
+        setSmartWheelEnabled,
         getDpiStages,
         setDpiStages,
         appSettings,
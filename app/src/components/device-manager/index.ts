@@ -14,4 +14,10 @@ export type {
     IDeviceInformation,
     DpiStage,
     IAppSettings,
+    LedZone,
+    LedBrightness,
+    LightingEffect,
+    ScrollMode,
+    ChargingLed,
+    ChargingEffects,
 } from "./types.ts";
\ No newline at end of file
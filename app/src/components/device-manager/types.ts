@@ -1,20 +1,48 @@
 export interface IDeviceInformation {
     batteryLevel: number;
     isCharging: boolean;
+    /** Charging state of the Mouse Dock Pro, null without a dock. */
+    dockCharging: boolean | null;
     pollingRate: PossiblePollingRates;
+    supportedPollingRates: PossiblePollingRates[];
     dpiXy: [number, number];
     backlightBrightness: number;
+    ledBrightness: LedBrightness;
     backlightColor: RGBColor;
     matrixBehavior: PossibleMatrixBehaviors;
     targetOs: TargetOs;
     smartWheelEnabled: boolean;
+    scrollAcceleration: boolean;
+    freeSpin: boolean;
+    idleTimeoutSecs: number | null;
+    lowBatteryThreshold: number | null;
+    firmwareVersion: string | null;
+    serialNumber: string | null;
     mouseWheelInverted: boolean;
     dpiStages: Array<DpiStage>;
 }
 
 export type TargetOs = 'windows' | 'linux' | 'macos' | 'unknown';
-export type PossiblePollingRates = 125 | 250 | 500 | 1000;
-export type PossibleMatrixBehaviors = 'none' | 'static';
+export type PossiblePollingRates = 125 | 250 | 500 | 1000 | 2000 | 4000 | 8000;
+export type PossibleMatrixBehaviors = 'none' | 'static' | 'breathing' | 'spectrum' | 'wave' | 'reactive' | 'starlight' | 'customFrame';
+export type LightingEffect =
+    | { type: 'none' }
+    | { type: 'static'; rgb: [number, number, number] }
+    | { type: 'breathing'; colors: Array<[number, number, number]> }
+    | { type: 'spectrum' }
+    | { type: 'wave'; reverse: boolean; speed: number }
+    | { type: 'reactive'; speed: number; rgb: [number, number, number] }
+    | { type: 'starlight'; speed: number; colors: Array<[number, number, number]> }
+    | { type: 'customFrame'; frame: Array<Array<[number, number, number]>> };
+
+export type LedZone = 'logo' | 'scrollWheel' | 'backlight' | 'leftSide' | 'rightSide';
+export type LedBrightness = Partial<Record<LedZone, number>>;
+
+export type ScrollMode = 'tactile' | 'freeSpin';
+
+export type ChargingLed = 'charging' | 'fastCharging' | 'fullyCharged';
+/** Effects shown while charging, states not listed keep the firmware's default. */
+export type ChargingEffects = Partial<Record<ChargingLed, LightingEffect>>;
 
 export type RGBColor = { r: number; g: number; b: number };
 export type DpiStage = {
@@ -29,9 +57,17 @@ export interface IAppSettings {
     dpiY: number;
     pollingRate: PossiblePollingRates;
     rgbColor: [number, number, number];
-    brightness: number;
+    brightness: LedBrightness;
+    lightingEffect: LightingEffect | null;
     scrollInverted: boolean;
     smartWheelEnabled: boolean;
+    scrollAcceleration: boolean;
+    scrollMode: ScrollMode;
+    idleTimeoutSecs: number | null;
+    lowBatteryThreshold: number | null;
+    chargingEffects: ChargingEffects;
+    dockLightingEffect: LightingEffect | null;
+    dockBrightness: number | null;
     autoUpdate: boolean;
     dpiStages: Array<DpiStage>;
 }
@@ -45,9 +81,20 @@ export interface IDeviceManagerApi {
     getBatteryLevel(): Promise<number>;
     getChargingStatus(): Promise<boolean>;
     setBacklightBrightness(brightness: number): Promise<void>;
+    setLedBrightness?(zone: LedZone, brightness: number): Promise<void>;
     setBacklightColor(color: RGBColor): Promise<void>;
+    setLightingEffect?(effect: LightingEffect): Promise<void>;
+    setCustomFrame?(frame: Array<Array<[number, number, number]>>): Promise<void>;
+    setChargingEffect?(state: ChargingLed, effect: LightingEffect): Promise<void>;
+    setDockLightingEffect?(effect: LightingEffect): Promise<void>;
+    setDockBrightness?(brightness: number): Promise<void>;
+    setArgbChannelColors?(channel: number, colors: Array<[number, number, number]>): Promise<void>;
     getTargetOs(): Promise<TargetOs>;
-    setSmartWheelEnabled?(enabled: boolean): Promise<void>;
+    setSmartWheelEnabled(enabled: boolean): Promise<void>;
+    setScrollAcceleration?(enabled: boolean): Promise<void>;
+    setFreeSpin?(enabled: boolean): Promise<void>;
+    setIdleTimeout?(seconds: number): Promise<void>;
+    setLowBatteryThreshold?(percentage: number): Promise<void>;
     setMouseWheelInverted(inverted: boolean): Promise<void>;
     getSavedSettings(): Promise<IAppSettings>;
     saveSettings(settings: IAppSettings): Promise<void>;
@@ -6,7 +6,10 @@ import {
     DeviceManagerProvider,
     IDeviceInformation,
     PossiblePollingRates,
-    DpiStage
+    DpiStage,
+    LedZone,
+    LightingEffect,
+    ChargingLed
 } from "../components/device-manager";
 import { invoke } from "@tauri-apps/api/core";
 import UpdateOverlay from "../components/update-overlay/UpdateOverlay";
@@ -45,11 +48,26 @@ function Root() {
                     console.log(`Setting mouse wheel inverted to ${inverted}`);
                     return invoke('set_mouse_wheel_inverted', { inverted });
                 },
-                //                 // Smartwheel deactivated for now
-                //                 async setSmartWheelEnabled(enabled: boolean): Promise<void> {
-                //                     console.log(`Setting smart wheel enabled to ${enabled}`);
-                //                     return invoke('set_device_smart_wheel', { enabled });
-                //                 },
+                async setSmartWheelEnabled(enabled: boolean): Promise<void> {
+                    console.log(`Setting smart wheel enabled to ${enabled}`);
+                    return invoke('set_device_smart_wheel', { enabled });
+                },
+                async setScrollAcceleration(enabled: boolean): Promise<void> {
+                    console.log(`Setting scroll acceleration to ${enabled}`);
+                    return invoke('set_device_scroll_acceleration', { enabled });
+                },
+                async setFreeSpin(enabled: boolean): Promise<void> {
+                    console.log(`Setting free spin to ${enabled}`);
+                    return invoke('set_device_free_spin', { enabled });
+                },
+                async setIdleTimeout(seconds: number): Promise<void> {
+                    console.log(`Setting idle timeout to ${seconds}s`);
+                    return invoke('set_device_idle_timeout', { seconds });
+                },
+                async setLowBatteryThreshold(percentage: number): Promise<void> {
+                    console.log(`Setting low battery threshold to ${percentage}%`);
+                    return invoke('set_device_low_battery_threshold', { percentage });
+                },
                 async setPollingRate(pollingRate: PossiblePollingRates): Promise<void> {
                     console.log(`Setting polling rate to ${pollingRate} Hz`);
                     return invoke('set_device_polling_rate', { pollingRate });
@@ -70,10 +88,38 @@ function Root() {
                     console.log(`Setting backlight brightness to ${brightness}`);
                     return invoke<void>("set_device_backlight_brightness", { brightness });
                 },
+                async setLedBrightness(zone: LedZone, brightness: number): Promise<void> {
+                    console.log(`Setting ${zone} brightness to ${brightness}`);
+                    return invoke<void>("set_device_led_brightness", { zone, brightness });
+                },
                 async setBacklightColor(color: { r: number; g: number; b: number }): Promise<void> {
                     console.log(`Setting backlight color to R: ${color.r}, G: ${color.g}, B: ${color.b}`);
                     return invoke<void>("set_device_matrix_backlight_static", { ...color });
                 },
+                async setLightingEffect(effect: LightingEffect): Promise<void> {
+                    console.log('Setting lighting effect:', effect);
+                    return invoke<void>("set_device_lighting_effect", { effect });
+                },
+                async setCustomFrame(frame: Array<Array<[number, number, number]>>): Promise<void> {
+                    console.log('Setting custom frame:', frame);
+                    return invoke<void>("set_device_custom_frame", { frame });
+                },
+                async setChargingEffect(state: ChargingLed, effect: LightingEffect): Promise<void> {
+                    console.log(`Setting ${state} effect:`, effect);
+                    return invoke<void>("set_device_charging_effect", { state, effect });
+                },
+                async setDockLightingEffect(effect: LightingEffect): Promise<void> {
+                    console.log('Setting dock lighting effect:', effect);
+                    return invoke<void>("set_dock_lighting_effect", { effect });
+                },
+                async setDockBrightness(brightness: number): Promise<void> {
+                    console.log(`Setting dock brightness to ${brightness}`);
+                    return invoke<void>("set_dock_brightness", { brightness });
+                },
+                async setArgbChannelColors(channel: number, colors: Array<[number, number, number]>): Promise<void> {
+                    console.log(`Setting ARGB channel ${channel}:`, colors);
+                    return invoke<void>("set_argb_channel_colors", { channel, colors });
+                },
                 async getDpiStages(): Promise<Array<DpiStage>> {
                     console.log('Fetching dpi stages');
                     return invoke("get_device_dpi_stages");
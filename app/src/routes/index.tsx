@@ -59,7 +59,7 @@ function Index() {
         );
     }
 
-    const { batteryLevel, isCharging } = deviceManager.deviceInformation;
+    const { batteryLevel, isCharging, dockCharging } = deviceManager.deviceInformation;
 
     const shouldShow = deviceManager.isInitialized && !deviceManager.error.isError;
 
@@ -83,7 +83,7 @@ function Index() {
                         </div>
                         <div className="flex gap-6 items-center mb-2">
                             {shouldShow &&
-                                <BatteryStatus batteryLevel={batteryLevel} isCharging={isCharging} />
+                                <BatteryStatus batteryLevel={batteryLevel} isCharging={isCharging} dockCharging={dockCharging} />
                             }
                             <Link
                                 to="/settings"
@@ -1,4 +1,4 @@
-import { PossiblePollingRates, useDeviceManager } from "../components/device-manager";
+import { useDeviceManager } from "../components/device-manager";
 import { NormalButton } from "../components/button";
 
 export const PollingRateSettings = () => {
@@ -28,15 +28,16 @@ export const PollingRateSettings = () => {
 
     const {
         pollingRate,
+        supportedPollingRates,
     } = deviceInformation!;
 
     return (
         <div>
             <div className="flex flex-col gap-3 mt-2">
-                {[125, 500, 1000].map(rate => (
+                {supportedPollingRates.map(rate => (
                     <NormalButton
                         key={rate}
-                        onClick={() => setPollingRate(rate as PossiblePollingRates)}
+                        onClick={() => setPollingRate(rate)}
                         text={`${rate}Hz`}
                         active={pollingRate === rate}
                     />
@@ -0,0 +1,222 @@
+use razer::{
+    Command, DeviceDescriptor, DeviceKind, DeviceMode, FirmwareVersion, GetDeviceMode,
+    GetFirmwareVersion, GetSerialNumber, RazerARGBReport, RazerReport, SetDeviceMode,
+    TransactionId, RAZER_USB_VENDOR_ID,
+};
+use crate::{protocol, Device, DriverError, DriverResult, PlatformUsbDriver, UsbDriver};
+
+/// A connected Razer device together with the protocol details of its product.
+///
+/// Every request is stamped with the descriptor's transaction id, advancing it per request
+/// if the device supports rolling ids, so stale responses are rejected by `validate_response`.
+///
+/// The transport is owned by the device and only reached through `protocol`, which is where
+/// the FFI calls happen, so the methods here are safe to call.
+pub struct RazerDevice<T: UsbDriver> {
+    transport: T,
+    descriptor: &'static DeviceDescriptor,
+    transaction_id: TransactionId,
+}
+
+impl RazerDevice<PlatformUsbDriver> {
+    pub fn open(descriptor: &'static DeviceDescriptor) -> DriverResult<Self> {
+        // SAFETY: the platform driver opens the device by id and owns the handle it returns.
+        let transport = unsafe { PlatformUsbDriver::new(RAZER_USB_VENDOR_ID, descriptor.product_id)? };
+        Ok(Self::new(transport, descriptor))
+    }
+}
+
+impl<T: UsbDriver> RazerDevice<T> {
+    pub fn new(transport: T, descriptor: &'static DeviceDescriptor) -> Self {
+        Self {
+            transport,
+            descriptor,
+            transaction_id: descriptor.transaction_id,
+        }
+    }
+
+    pub fn descriptor(&self) -> &'static DeviceDescriptor {
+        self.descriptor
+    }
+
+    fn next_transaction_id(&mut self) -> TransactionId {
+        let transaction_id = self.transaction_id;
+        if self.descriptor.rolling_transaction_ids {
+            self.transaction_id = transaction_id.next();
+        }
+        transaction_id
+    }
+
+    #[cfg(test)]
+    pub(crate) fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    pub fn send_report(&mut self, request: &mut RazerReport) -> DriverResult<RazerReport> {
+        request.set_transaction_id(self.next_transaction_id());
+        // SAFETY: `transport` was opened by `UsbDriver::new` and is only used by this device.
+        unsafe { protocol::send_report(&mut self.transport, request) }
+    }
+
+    pub fn execute<C: Command>(&mut self, command: C) -> DriverResult<C::Response> {
+        let mut request = command.report();
+        request.set_transaction_id(self.next_transaction_id());
+        // SAFETY: see `send_report`.
+        unsafe { protocol::execute_report(&mut self.transport, request) }
+    }
+
+    pub fn firmware_version(&mut self) -> DriverResult<FirmwareVersion> {
+        self.execute(GetFirmwareVersion)
+    }
+
+    pub fn serial_number(&mut self) -> DriverResult<String> {
+        Ok(self.execute(GetSerialNumber)?.serial)
+    }
+
+    /// Sends the reports of a multi-packet transfer, see `razer::split_payload`.
+    /// Every report is answered on its own and has to succeed before the next one is sent.
+    pub fn send_payload(&mut self, reports: Vec<RazerReport>) -> DriverResult<()> {
+        for mut report in reports {
+            self.send_report(&mut report)?.check_status()?;
+        }
+        Ok(())
+    }
+
+    /// Switches between firmware (normal) and host (driver) controlled buttons and lighting.
+    pub fn set_device_mode(&mut self, mode: DeviceMode) -> DriverResult<()> {
+        self.execute(SetDeviceMode { mode })
+    }
+
+    pub fn device_mode(&mut self) -> DriverResult<DeviceMode> {
+        self.execute(GetDeviceMode)
+    }
+
+    /// Streams the colours of one channel to an ARGB controller.
+    pub fn send_argb_frame(&mut self, channel: u8, colors: &[[u8; 3]]) -> DriverResult<()> {
+        if self.descriptor.kind != DeviceKind::ArgbController {
+            return Err(DriverError::InvalidParameter(format!("{} is no ARGB controller", self.descriptor.name)));
+        }
+        // SAFETY: see `send_report`.
+        unsafe { protocol::send_argb_report(&mut self.transport, &RazerARGBReport::new(channel, colors)?) }
+    }
+}
+
+/// Fills in serial number and firmware version if `device` is a supported Razer device.
+/// Wireless devices that are asleep don't answer and keep both empty.
+///
+/// Unlike `UsbDriver::list_devices` this opens the device and talks to it, so only call it
+/// for the devices whose details are shown.
+///
+/// # Safety
+///
+/// Opens a transport through `T::new`, see the platform's `UsbDriver` implementation.
+pub unsafe fn identify<T: UsbDriver>(device: &mut Device) {
+    if device.vendor_id != RAZER_USB_VENDOR_ID as u32 {
+        return;
+    }
+    let Some(descriptor) = razer::find_device(device.product_id as u16) else {
+        return;
+    };
+    let Ok(transport) = T::new(device.vendor_id as u16, device.product_id as u16) else {
+        return;
+    };
+
+    let mut razer_device = RazerDevice::new(transport, descriptor);
+    device.serial_number = razer_device.serial_number().ok();
+    device.firmware_version = razer_device.firmware_version().ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usb::mock::{response_bytes, MockUsbDriver};
+    use razer::{
+        split_payload, GetBatteryLevel, ReportError, TransactionId, BASILISK_V3_PRO_WIRELESS,
+        RAZER_BASILISK_V3_PRO_ID, RAZER_CMD_FAILURE, RAZER_CMD_SUCCESSFUL,
+    };
+
+    #[test]
+    fn test_descriptor_lookup_sets_transaction_id() {
+        let descriptor = razer::find_device(RAZER_BASILISK_V3_PRO_ID).unwrap();
+        assert_eq!(descriptor, &BASILISK_V3_PRO_WIRELESS);
+        assert!(razer::find_device(0xFFFF).is_none());
+
+        let mut device = RazerDevice::new(MockUsbDriver::echoing(), descriptor);
+        device.execute(GetBatteryLevel).unwrap();
+        assert_eq!(device.transport.sent[0].transaction_id(), descriptor.transaction_id);
+    }
+
+    #[test]
+    fn test_rolling_transaction_ids() {
+        static ROLLING: DeviceDescriptor = DeviceDescriptor { rolling_transaction_ids: true, ..BASILISK_V3_PRO_WIRELESS };
+        let mut device = RazerDevice::new(MockUsbDriver::echoing(), &ROLLING);
+        device.execute(GetBatteryLevel).unwrap();
+        device.execute(GetBatteryLevel).unwrap();
+        let ids: Vec<u8> = device.transport.sent.iter().map(|r| r.transaction_id().raw()).collect();
+        assert_eq!(ids, vec![0x1f, 0x00]);
+
+        let mut fixed = RazerDevice::new(MockUsbDriver::echoing(), &BASILISK_V3_PRO_WIRELESS);
+        fixed.execute(GetBatteryLevel).unwrap();
+        fixed.execute(GetBatteryLevel).unwrap();
+        assert!(fixed.transport.sent.iter().all(|r| r.transaction_id() == BASILISK_V3_PRO_WIRELESS.transaction_id));
+    }
+
+    #[test]
+    fn test_execute_rejects_failed_status() {
+        let transport = MockUsbDriver::answering(|request| response_bytes(*request, RAZER_CMD_FAILURE));
+        let mut device = RazerDevice::new(transport, &BASILISK_V3_PRO_WIRELESS);
+
+        let result = device.execute(GetBatteryLevel);
+        assert!(matches!(result, Err(DriverError::Protocol(ReportError::CommandFailure))));
+    }
+
+    #[test]
+    fn test_execute_rejects_response_of_other_transaction() {
+        let transport = MockUsbDriver::answering(|request| {
+            let mut stale = *request;
+            stale.set_transaction_id(TransactionId::new(1, 0x1f));
+            response_bytes(stale, RAZER_CMD_SUCCESSFUL)
+        });
+        let mut device = RazerDevice::new(transport, &BASILISK_V3_PRO_WIRELESS);
+
+        let result = device.execute(GetBatteryLevel);
+        assert!(matches!(
+            result,
+            Err(DriverError::Protocol(ReportError::ResponseMismatch("transaction id", 0x1f, 0x3f)))
+        ));
+    }
+
+    #[test]
+    fn test_send_payload_stops_at_first_failure() {
+        let mut answered = 0;
+        let transport = MockUsbDriver::answering(move |request| {
+            answered += 1;
+            let status = if answered == 2 { RAZER_CMD_FAILURE } else { RAZER_CMD_SUCCESSFUL };
+            response_bytes(*request, status)
+        });
+        let mut device = RazerDevice::new(transport, &BASILISK_V3_PRO_WIRELESS);
+
+        let reports = split_payload(0x06, 0x09, &[0xAB; 200]).unwrap();
+        assert_eq!(reports.len(), 3);
+        assert!(device.send_payload(reports).is_err());
+        assert_eq!(device.transport.sent.len(), 2);
+    }
+
+    #[test]
+    fn test_identify_only_asks_supported_devices() {
+        let mut mouse = Device {
+            name: "Basilisk".to_string(),
+            vendor_id: RAZER_USB_VENDOR_ID as u32,
+            product_id: RAZER_BASILISK_V3_PRO_ID as u32,
+            serial_number: None,
+            firmware_version: None,
+        };
+        unsafe { identify::<MockUsbDriver>(&mut mouse) };
+        assert_eq!(mouse.firmware_version, Some(FirmwareVersion { major: 0, minor: 0 }));
+
+        let mut keyboard = Device { vendor_id: 0x046D, ..mouse.clone() };
+        keyboard.firmware_version = None;
+        unsafe { identify::<MockUsbDriver>(&mut keyboard) };
+        assert_eq!(keyboard.firmware_version, None);
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt;
+use razer::ReportError;
 
 #[derive(Debug, Clone)]
 pub enum DriverError {
@@ -9,6 +10,7 @@ pub enum DriverError {
     InvalidParameter(String),
     IoError(String),
     NotImplemented(String),
+    Protocol(ReportError),
     Other(String),
 }
 
@@ -22,6 +24,7 @@ impl fmt::Display for DriverError {
             DriverError::InvalidParameter(e) => write!(f, "Invalid parameter: {}", e),
             DriverError::IoError(e) => write!(f, "IO error: {}", e),
             DriverError::NotImplemented(e) => write!(f, "Not implemented: {}", e),
+            DriverError::Protocol(e) => write!(f, "Protocol error: {}", e),
             DriverError::Other(e) => write!(f, "Error: {}", e),
         }
     }
@@ -47,6 +50,12 @@ impl From<std::io::Error> for DriverError {
     }
 }
 
+impl From<ReportError> for DriverError {
+    fn from(e: ReportError) -> Self {
+        DriverError::Protocol(e)
+    }
+}
+
 impl From<DriverError> for String {
     fn from(e: DriverError) -> Self {
         e.to_string()
@@ -1,10 +1,18 @@
 mod usb;
 mod preferences;
+mod protocol;
+mod device;
+mod lighting;
+mod power;
+mod polling;
+mod scroll;
 pub mod settings;
 pub mod error;
 
-pub use usb::{UsbDriver, PlatformUsbDriver };
+pub use usb::{Device, UsbDriver, PlatformUsbDriver };
 pub use preferences::{PreferencesDriver, PlatformPreferencesDriver};
+pub use protocol::{execute, send_argb_report, send_report};
+pub use device::{identify, RazerDevice};
 pub use error::{DriverError};
 
 pub type DriverResult<T> = Result<T, DriverError>;
\ No newline at end of file
@@ -0,0 +1,123 @@
+use razer::{
+    EffectColors, GetLedBrightness, GetStandardLedBrightness, LightingProtocol, MatrixEffect,
+    RazerClassicEffectId, SetLedBrightness, SetLedEffect, SetLedRgb, SetLedState,
+    SetMatrixEffect, SetStandardLedBrightness, LOGO_LED, ZERO_LED,
+};
+use crate::{DriverError, DriverResult, RazerDevice, UsbDriver};
+
+/// Lighting for both LED command sets, picked by the device's `DeviceDescriptor::lighting`.
+///
+/// Standard devices have no id addressing every zone, `ZERO_LED` is sent to their logo
+/// instead, which every one of them has, and they only know the classic effects.
+impl<T: UsbDriver> RazerDevice<T> {
+    pub fn set_led_brightness(&mut self, led_id: u8, brightness: u8) -> DriverResult<()> {
+        match self.descriptor().lighting {
+            LightingProtocol::Extended => self.execute(SetLedBrightness { led_id, brightness }),
+            LightingProtocol::Standard => self.execute(SetStandardLedBrightness {
+                led_id: standard_led_id(led_id),
+                brightness,
+            }),
+            LightingProtocol::None => Err(self.no_lighting()),
+        }
+    }
+
+    pub fn led_brightness(&mut self, led_id: u8) -> DriverResult<u8> {
+        let led = match self.descriptor().lighting {
+            LightingProtocol::Extended => self.execute(GetLedBrightness { led_id })?,
+            LightingProtocol::Standard => self.execute(GetStandardLedBrightness {
+                led_id: standard_led_id(led_id),
+            })?,
+            LightingProtocol::None => return Err(self.no_lighting()),
+        };
+        Ok(led.brightness)
+    }
+
+    pub fn set_lighting_effect(&mut self, led_id: u8, effect: MatrixEffect) -> DriverResult<()> {
+        match self.descriptor().lighting {
+            LightingProtocol::Extended => return self.execute(SetMatrixEffect::new(led_id, effect)?),
+            LightingProtocol::None => return Err(self.no_lighting()),
+            LightingProtocol::Standard => {}
+        }
+
+        let led_id = standard_led_id(led_id);
+        let (effect_id, rgb) = match effect {
+            MatrixEffect::None => return self.execute(SetLedState { led_id, on: false }),
+            MatrixEffect::Static(rgb) => (RazerClassicEffectId::ClassicEffectStatic, Some(rgb)),
+            MatrixEffect::Breathing(EffectColors::Single(rgb)) => (RazerClassicEffectId::ClassicEffectBreathing, Some(rgb)),
+            MatrixEffect::Spectrum => (RazerClassicEffectId::ClassicEffectSpectrum, None),
+            effect => {
+                return Err(DriverError::NotImplemented(format!(
+                    "{:?} is not available with the standard LED commands",
+                    effect
+                )))
+            }
+        };
+
+        if let Some(rgb) = rgb {
+            self.execute(SetLedRgb { led_id, rgb })?;
+        }
+        self.execute(SetLedEffect { led_id, effect: effect_id })?;
+        self.execute(SetLedState { led_id, on: true })
+    }
+
+    fn no_lighting(&self) -> DriverError {
+        DriverError::NotImplemented(format!("{} has no LEDs", self.descriptor().name))
+    }
+}
+
+fn standard_led_id(led_id: u8) -> u8 {
+    if led_id == ZERO_LED {
+        LOGO_LED
+    } else {
+        led_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usb::mock::MockUsbDriver;
+    use razer::{BASILISK_V3_PRO_WIRED, DEATHADDER_CHROMA, HYPERPOLLING_WIRELESS_DONGLE};
+
+    fn sent_commands(device: &RazerDevice<MockUsbDriver>) -> Vec<(u8, u8, u8)> {
+        device
+            .transport()
+            .sent
+            .iter()
+            .map(|report| (report.command_class(), report.command_id().raw(), report.arguments[1]))
+            .collect()
+    }
+
+    #[test]
+    fn test_standard_lighting_uses_class_3() {
+        let mut device = RazerDevice::new(MockUsbDriver::echoing(), &DEATHADDER_CHROMA);
+
+        device.set_lighting_effect(ZERO_LED, MatrixEffect::Static([0xFF, 0x00, 0x00])).unwrap();
+        device.set_led_brightness(ZERO_LED, 0x80).unwrap();
+        assert_eq!(
+            sent_commands(&device),
+            vec![(0x03, 0x01, LOGO_LED), (0x03, 0x02, LOGO_LED), (0x03, 0x00, LOGO_LED), (0x03, 0x03, LOGO_LED)]
+        );
+
+        assert!(device.set_lighting_effect(ZERO_LED, MatrixEffect::Starlight { speed: 1, colors: EffectColors::Random }).is_err());
+        assert_eq!(device.transport().sent.len(), 4);
+    }
+
+    #[test]
+    fn test_extended_lighting_uses_class_f() {
+        let mut device = RazerDevice::new(MockUsbDriver::echoing(), &BASILISK_V3_PRO_WIRED);
+
+        device.set_lighting_effect(ZERO_LED, MatrixEffect::Static([0xFF, 0x00, 0x00])).unwrap();
+        device.set_led_brightness(ZERO_LED, 0x80).unwrap();
+        assert_eq!(sent_commands(&device), vec![(0x0F, 0x02, ZERO_LED), (0x0F, 0x04, ZERO_LED)]);
+    }
+
+    #[test]
+    fn test_device_without_leds_sends_nothing() {
+        let mut device = RazerDevice::new(MockUsbDriver::echoing(), &HYPERPOLLING_WIRELESS_DONGLE);
+
+        assert!(matches!(device.set_lighting_effect(ZERO_LED, MatrixEffect::Spectrum), Err(DriverError::NotImplemented(_))));
+        assert!(matches!(device.set_led_brightness(ZERO_LED, 0x80), Err(DriverError::NotImplemented(_))));
+        assert!(device.transport().sent.is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+use razer::{
+    GetExtendedPollingRate, GetPollingRate, PollingProtocol, SetExtendedPollingRate,
+    SetPollingRate,
+};
+use crate::{DriverError, DriverResult, RazerDevice, UsbDriver};
+
+/// Polling rate, sent with the command the device's descriptor asks for.
+impl<T: UsbDriver> RazerDevice<T> {
+    pub fn set_polling_rate(&mut self, polling_rate: u16) -> DriverResult<()> {
+        let descriptor = self.descriptor();
+        if !descriptor.polling_rates.contains(&polling_rate) {
+            return Err(DriverError::InvalidParameter(format!(
+                "{} doesn't support a polling rate of {}Hz",
+                descriptor.name, polling_rate
+            )));
+        }
+
+        match descriptor.polling {
+            PollingProtocol::Standard => self.execute(SetPollingRate::new(polling_rate)?),
+            PollingProtocol::Extended => self.execute(SetExtendedPollingRate::new(polling_rate)?),
+        }
+    }
+
+    pub fn polling_rate(&mut self) -> DriverResult<u16> {
+        match self.descriptor().polling {
+            PollingProtocol::Standard => Ok(self.execute(GetPollingRate)?.0),
+            PollingProtocol::Extended => Ok(self.execute(GetExtendedPollingRate)?.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usb::mock::MockUsbDriver;
+    use razer::{BASILISK_V3_PRO_WIRED, HYPERPOLLING_WIRELESS_DONGLE};
+
+    #[test]
+    fn test_extended_polling_rate() {
+        let mut device = RazerDevice::new(MockUsbDriver::echoing(), &HYPERPOLLING_WIRELESS_DONGLE);
+
+        device.set_polling_rate(8000).unwrap();
+        let request = device.transport().sent[0];
+        assert_eq!((request.command_class(), request.command_id().raw()), (0x00, 0x40));
+        assert_eq!(request.arguments[..2], [0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_polling_rate_not_listed_is_rejected() {
+        let mut mouse = RazerDevice::new(MockUsbDriver::echoing(), &BASILISK_V3_PRO_WIRED);
+        assert!(matches!(mouse.set_polling_rate(8000), Err(DriverError::InvalidParameter(_))));
+        assert!(mouse.transport().sent.is_empty());
+
+        mouse.set_polling_rate(500).unwrap();
+        let request = mouse.transport().sent[0];
+        assert_eq!((request.command_class(), request.command_id().raw()), (0x00, 0x05));
+    }
+}
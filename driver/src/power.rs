@@ -0,0 +1,22 @@
+use razer::{GetChargeLowThreshold, GetIdleTimeout, SetChargeLowThreshold, SetIdleTimeout};
+use crate::{DriverResult, RazerDevice, UsbDriver};
+
+/// Power management of wireless devices.
+impl<T: UsbDriver> RazerDevice<T> {
+    pub fn set_idle_timeout(&mut self, seconds: u16) -> DriverResult<()> {
+        self.execute(SetIdleTimeout::new(seconds)?)
+    }
+
+    pub fn idle_timeout(&mut self) -> DriverResult<u16> {
+        Ok(self.execute(GetIdleTimeout)?.seconds)
+    }
+
+    /// Battery percentage below which the device enters power saving mode.
+    pub fn set_low_battery_threshold(&mut self, percentage: u8) -> DriverResult<()> {
+        self.execute(SetChargeLowThreshold::from_percentage(percentage)?)
+    }
+
+    pub fn low_battery_threshold(&mut self) -> DriverResult<u8> {
+        Ok(self.execute(GetChargeLowThreshold)?.percentage())
+    }
+}
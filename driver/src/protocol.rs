@@ -0,0 +1,60 @@
+use std::time::Duration;
+use razer::{
+    Command, FromReport, RazerARGBReport, RazerReport, RAZER_ACCESSORY_WAIT_MIN_US,
+    RAZER_NEW_MOUSE_RECEIVER_WAIT_MAX_US, RAZER_USB_REPORT_LEN,
+};
+use crate::{DriverResult, UsbDriver};
+
+/// Sends a single report and returns the device's answer to it.
+///
+/// The response is checked for length, CRC and that it echoes the request, but not for its
+/// status byte, so callers can tell a sleeping wireless mouse (timeout) from a failed transfer.
+///
+/// # Safety
+///
+/// `transport` has to be a device opened by `UsbDriver::new` that is still open.
+pub unsafe fn send_report<T: UsbDriver>(transport: &mut T, request: &mut RazerReport) -> DriverResult<RazerReport> {
+    request.finalize();
+
+    let data = transport.get_feature_report(
+        request.to_hid_bytes().as_slice(),
+        0x00,
+        Duration::from_micros(RAZER_NEW_MOUSE_RECEIVER_WAIT_MAX_US as u64),
+        RAZER_USB_REPORT_LEN as u16,
+    )?;
+
+    let response = RazerReport::try_from(data.as_slice())?;
+    response.validate_response(request)?;
+    Ok(response)
+}
+
+/// Executes a command and decodes its response, failing unless the device reported success.
+///
+/// # Safety
+///
+/// See `send_report`.
+pub unsafe fn execute<T: UsbDriver, C: Command>(transport: &mut T, command: C) -> DriverResult<C::Response> {
+    execute_report(transport, command.report())
+}
+
+pub(crate) unsafe fn execute_report<T: UsbDriver, R: FromReport>(transport: &mut T, mut request: RazerReport) -> DriverResult<R> {
+    let response = send_report(transport, &mut request)?;
+    response.check_status()?;
+    Ok(R::from_report(&response)?)
+}
+
+/// Writes an ARGB colour frame as its own numbered HID report on interface 1.
+/// The controller does not answer these.
+///
+/// # Safety
+///
+/// See `send_report`.
+pub unsafe fn send_argb_report<T: UsbDriver>(transport: &mut T, report: &RazerARGBReport) -> DriverResult<()> {
+    transport.send_control_msg(
+        0x09,
+        0x300 | report.report_id() as u16,
+        0x01,
+        report.to_bytes().as_slice(),
+        Duration::from_micros(RAZER_ACCESSORY_WAIT_MIN_US as u64),
+    )
+}
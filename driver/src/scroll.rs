@@ -0,0 +1,32 @@
+use razer::{
+    GetScrollAcceleration, GetScrollMode, GetScrollSmartReel, ScrollMode, SetScrollAcceleration,
+    SetScrollMode, SetScrollSmartReel,
+};
+use crate::{DriverResult, RazerDevice, UsbDriver};
+
+/// Scroll wheel settings stored on the device.
+impl<T: UsbDriver> RazerDevice<T> {
+    pub fn set_smart_reel(&mut self, enabled: bool) -> DriverResult<()> {
+        self.execute(SetScrollSmartReel { enabled })
+    }
+
+    pub fn smart_reel(&mut self) -> DriverResult<bool> {
+        Ok(self.execute(GetScrollSmartReel)?.enabled)
+    }
+
+    pub fn set_scroll_acceleration(&mut self, enabled: bool) -> DriverResult<()> {
+        self.execute(SetScrollAcceleration { enabled })
+    }
+
+    pub fn scroll_acceleration(&mut self) -> DriverResult<bool> {
+        Ok(self.execute(GetScrollAcceleration)?.enabled)
+    }
+
+    pub fn set_scroll_mode(&mut self, mode: ScrollMode) -> DriverResult<()> {
+        self.execute(SetScrollMode { mode })
+    }
+
+    pub fn scroll_mode(&mut self) -> DriverResult<ScrollMode> {
+        self.execute(GetScrollMode)
+    }
+}
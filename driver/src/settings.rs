@@ -1,7 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use crate::DriverResult;
+use razer::{
+    EffectColors, MatrixEffect, ReportError, ScrollMode, SetDpiStages, WaveDirection,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,6 +16,114 @@ pub struct DpiStage {
     pub active: bool,
 }
 
+/// Lighting zones whose brightness can be set independently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LedZone {
+    Logo,
+    ScrollWheel,
+    Backlight,
+    LeftSide,
+    RightSide,
+}
+
+impl LedZone {
+    pub const ALL: [LedZone; 5] = [
+        LedZone::Logo,
+        LedZone::ScrollWheel,
+        LedZone::Backlight,
+        LedZone::LeftSide,
+        LedZone::RightSide,
+    ];
+
+    pub fn led_id(&self) -> u8 {
+        match self {
+            LedZone::Logo => razer::LOGO_LED,
+            LedZone::ScrollWheel => razer::SCROLL_WHEEL_LED,
+            LedZone::Backlight => razer::BACKLIGHT_LED,
+            LedZone::LeftSide => razer::LEFT_SIDE_LED,
+            LedZone::RightSide => razer::RIGHT_SIDE_LED,
+        }
+    }
+}
+
+/// LEDs lit by the firmware while the mouse charges, each takes its own effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChargingLed {
+    Charging,
+    FastCharging,
+    FullyCharged,
+}
+
+impl ChargingLed {
+    pub fn led_id(&self) -> u8 {
+        match self {
+            ChargingLed::Charging => razer::CHARGING_LED,
+            ChargingLed::FastCharging => razer::FAST_CHARGING_LED,
+            ChargingLed::FullyCharged => razer::FULLY_CHARGED_LED,
+        }
+    }
+}
+
+/// Persisted lighting effect. `colors` holds zero (random), one or two colours.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LightingEffect {
+    None,
+    Static { rgb: [u8; 3] },
+    Breathing { colors: Vec<[u8; 3]> },
+    Spectrum,
+    Wave { reverse: bool, speed: u8 },
+    Reactive { speed: u8, rgb: [u8; 3] },
+    Starlight { speed: u8, colors: Vec<[u8; 3]> },
+    /// One colour per LED, row by row.
+    CustomFrame { frame: Vec<Vec<[u8; 3]>> },
+}
+
+impl LightingEffect {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LightingEffect::None => "none",
+            LightingEffect::Static { .. } => "static",
+            LightingEffect::Breathing { .. } => "breathing",
+            LightingEffect::Spectrum => "spectrum",
+            LightingEffect::Wave { .. } => "wave",
+            LightingEffect::Reactive { .. } => "reactive",
+            LightingEffect::Starlight { .. } => "starlight",
+            LightingEffect::CustomFrame { .. } => "customFrame",
+        }
+    }
+
+    pub fn matrix_effect(&self) -> Result<MatrixEffect, ReportError> {
+        Ok(match self {
+            LightingEffect::None => MatrixEffect::None,
+            LightingEffect::Static { rgb } => MatrixEffect::Static(*rgb),
+            LightingEffect::Breathing { colors } => MatrixEffect::Breathing(effect_colors(colors)?),
+            LightingEffect::Spectrum => MatrixEffect::Spectrum,
+            LightingEffect::Wave { reverse, speed } => MatrixEffect::Wave {
+                direction: if *reverse { WaveDirection::Reverse } else { WaveDirection::Forward },
+                speed: *speed,
+            },
+            LightingEffect::Reactive { speed, rgb } => MatrixEffect::Reactive { speed: *speed, rgb: *rgb },
+            LightingEffect::Starlight { speed, colors } => MatrixEffect::Starlight {
+                speed: *speed,
+                colors: effect_colors(colors)?,
+            },
+            LightingEffect::CustomFrame { .. } => MatrixEffect::CustomFrame,
+        })
+    }
+}
+
+fn effect_colors(colors: &[[u8; 3]]) -> Result<EffectColors, ReportError> {
+    match colors {
+        [] => Ok(EffectColors::Random),
+        [rgb] => Ok(EffectColors::Single(*rgb)),
+        [first, second] => Ok(EffectColors::Dual(*first, *second)),
+        _ => Err(ReportError::InvalidArgument(format!("{} colours given, at most 2 are supported", colors.len()))),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MouseSettings {
@@ -23,12 +135,33 @@ pub struct MouseSettings {
     pub polling_rate: u16,
     #[serde(default = "default_rgb_color")]
     pub rgb_color: [u8; 3],
-    #[serde(default = "default_brightness")]
-    pub brightness: u8,
+    #[serde(default = "default_brightness", deserialize_with = "deserialize_brightness")]
+    pub brightness: BTreeMap<LedZone, u8>,
+    /// `None` for settings saved before effects existed, see [`MouseSettings::current_lighting_effect`].
+    #[serde(default)]
+    pub lighting_effect: Option<LightingEffect>,
     #[serde(default = "default_scroll_inverted")]
     pub scroll_inverted: bool,
     #[serde(default = "default_smart_wheel_enabled")]
     pub smart_wheel_enabled: bool,
+    #[serde(default = "default_scroll_acceleration")]
+    pub scroll_acceleration: bool,
+    #[serde(default = "default_scroll_mode", with = "scroll_mode_serde")]
+    pub scroll_mode: ScrollMode,
+    /// Seconds until the wireless mouse goes to sleep, `None` leaves the device's own setting.
+    #[serde(default)]
+    pub idle_timeout_secs: Option<u16>,
+    /// Battery percentage that starts power saving, `None` leaves the device's own setting.
+    #[serde(default)]
+    pub low_battery_threshold: Option<u8>,
+    /// Effects shown while charging, states not listed keep the firmware's default.
+    #[serde(default)]
+    pub charging_effects: BTreeMap<ChargingLed, LightingEffect>,
+    /// Lighting of the Mouse Dock Pro, `None` leaves the dock's own setting.
+    #[serde(default)]
+    pub dock_lighting_effect: Option<LightingEffect>,
+    #[serde(default)]
+    pub dock_brightness: Option<u8>,
     #[serde(default = "default_auto_update")]
     pub auto_update: bool,
     #[serde(default = "default_dpi_stages")]
@@ -39,9 +172,11 @@ fn default_dpi_x() -> u16 { 3200 }
 fn default_dpi_y() -> u16 { 3200 }
 fn default_polling_rate() -> u16 { 1000 }
 fn default_rgb_color() -> [u8; 3] { [255, 255, 255] }
-fn default_brightness() -> u8 { 100 }
+fn default_brightness() -> BTreeMap<LedZone, u8> { uniform_brightness(100) }
 fn default_scroll_inverted() -> bool { false }
 fn default_smart_wheel_enabled() -> bool { false }
+fn default_scroll_acceleration() -> bool { false }
+fn default_scroll_mode() -> ScrollMode { ScrollMode::Tactile }
 fn default_auto_update() -> bool { false }
 fn default_dpi_stages() -> Vec<DpiStage> {
     vec![
@@ -60,9 +195,17 @@ impl Default for MouseSettings {
             dpi_y: 3200,
             polling_rate: 1000,
             rgb_color: [255, 255, 255],
-            brightness: 100,
+            brightness: default_brightness(),
+            lighting_effect: None,
             scroll_inverted: false,
             smart_wheel_enabled: false,
+            scroll_acceleration: false,
+            scroll_mode: ScrollMode::Tactile,
+            idle_timeout_secs: None,
+            low_battery_threshold: None,
+            charging_effects: BTreeMap::new(),
+            dock_lighting_effect: None,
+            dock_brightness: None,
             auto_update: false,
             dpi_stages: vec![
                 DpiStage { dpi_x: 400, dpi_y: 400, stage: 1, active: false },
@@ -75,7 +218,77 @@ impl Default for MouseSettings {
     }
 }
 
+/// `ScrollMode` lives in the dependency free `razer` crate, so it is stored by name here.
+mod scroll_mode_serde {
+    use razer::ScrollMode;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(mode: &ScrollMode, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match mode {
+            ScrollMode::Tactile => "tactile",
+            ScrollMode::FreeSpin => "freeSpin",
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ScrollMode, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "tactile" => Ok(ScrollMode::Tactile),
+            "freeSpin" => Ok(ScrollMode::FreeSpin),
+            other => Err(de::Error::unknown_variant(other, &["tactile", "freeSpin"])),
+        }
+    }
+}
+
+/// Same brightness for every zone.
+pub fn uniform_brightness(brightness: u8) -> BTreeMap<LedZone, u8> {
+    LedZone::ALL.iter().map(|zone| (*zone, brightness)).collect()
+}
+
+/// Builds the command for `stages`, the active one defaults to the first.
+pub fn dpi_stages_command(stages: &[DpiStage]) -> Result<SetDpiStages, ReportError> {
+    let active_dpi_stage = stages
+        .iter()
+        .find(|s| s.active)
+        .map(|s| s.stage)
+        .unwrap_or(1);
+    let dpi_stages: Vec<razer::DpiStage> = stages
+        .iter()
+        .map(|dpi_stage| razer::DpiStage {
+            stage: dpi_stage.stage,
+            dpi_x: dpi_stage.dpi_x,
+            dpi_y: dpi_stage.dpi_y,
+        })
+        .collect();
+
+    SetDpiStages::new(active_dpi_stage, dpi_stages)
+}
+
+/// Older settings files stored a single `brightness` number for all zones.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BrightnessSetting {
+    Uniform(u8),
+    PerZone(BTreeMap<LedZone, u8>),
+}
+
+fn deserialize_brightness<'de, D>(deserializer: D) -> Result<BTreeMap<LedZone, u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match BrightnessSetting::deserialize(deserializer)? {
+        BrightnessSetting::Uniform(brightness) => uniform_brightness(brightness),
+        BrightnessSetting::PerZone(zones) => zones,
+    })
+}
+
 impl MouseSettings {
+    /// The effect to apply, falling back to the static `rgb_color`.
+    pub fn current_lighting_effect(&self) -> LightingEffect {
+        self.lighting_effect
+            .clone()
+            .unwrap_or(LightingEffect::Static { rgb: self.rgb_color })
+    }
+
     /// Load settings from a JSON file. Returns default settings if file doesn't exist.
     pub fn load(path: &Path) -> DriverResult<Self> {
         if !path.exists() {
@@ -121,9 +334,15 @@ mod tests {
         assert_eq!(settings.dpi_y, 3200);
         assert_eq!(settings.polling_rate, 1000);
         assert_eq!(settings.rgb_color, [255, 255, 255]);
-        assert_eq!(settings.brightness, 100);
+        assert_eq!(settings.current_lighting_effect(), LightingEffect::Static { rgb: [255, 255, 255] });
+        assert_eq!(settings.brightness.len(), 5);
+        assert!(settings.brightness.values().all(|b| *b == 100));
         assert_eq!(settings.scroll_inverted, false);
         assert_eq!(settings.smart_wheel_enabled, false);
+        assert_eq!(settings.scroll_acceleration, false);
+        assert_eq!(settings.scroll_mode, ScrollMode::Tactile);
+        assert_eq!(settings.idle_timeout_secs, None);
+        assert_eq!(settings.low_battery_threshold, None);
         assert_eq!(settings.auto_update, false);
         assert_eq!(settings.dpi_stages.len(), 5);
     }
@@ -142,9 +361,20 @@ mod tests {
             dpi_y: 1600,
             polling_rate: 500,
             rgb_color: [255, 0, 0],
-            brightness: 50,
+            brightness: BTreeMap::from([(LedZone::ScrollWheel, 20), (LedZone::Backlight, 100)]),
+            lighting_effect: Some(LightingEffect::Wave { reverse: true, speed: 40 }),
             scroll_inverted: true,
             smart_wheel_enabled: false,
+            scroll_acceleration: true,
+            scroll_mode: ScrollMode::FreeSpin,
+            idle_timeout_secs: Some(600),
+            low_battery_threshold: Some(15),
+            charging_effects: BTreeMap::from([
+                (ChargingLed::Charging, LightingEffect::Static { rgb: [40, 0, 0] }),
+                (ChargingLed::FullyCharged, LightingEffect::None),
+            ]),
+            dock_lighting_effect: Some(LightingEffect::Spectrum),
+            dock_brightness: Some(60),
             auto_update: true,
             dpi_stages: vec![],
         };
@@ -160,8 +390,17 @@ mod tests {
         assert_eq!(loaded_settings.dpi_y, 1600);
         assert_eq!(loaded_settings.polling_rate, 500);
         assert_eq!(loaded_settings.rgb_color, [255, 0, 0]);
-        assert_eq!(loaded_settings.brightness, 50);
+        assert_eq!(loaded_settings.brightness[&LedZone::ScrollWheel], 20);
+        assert_eq!(loaded_settings.brightness[&LedZone::Backlight], 100);
+        assert_eq!(loaded_settings.lighting_effect, Some(LightingEffect::Wave { reverse: true, speed: 40 }));
         assert_eq!(loaded_settings.scroll_inverted, true);
+        assert_eq!(loaded_settings.scroll_acceleration, true);
+        assert_eq!(loaded_settings.scroll_mode, ScrollMode::FreeSpin);
+        assert_eq!(loaded_settings.idle_timeout_secs, Some(600));
+        assert_eq!(loaded_settings.low_battery_threshold, Some(15));
+        assert_eq!(loaded_settings.charging_effects, original_settings.charging_effects);
+        assert_eq!(loaded_settings.dock_lighting_effect, Some(LightingEffect::Spectrum));
+        assert_eq!(loaded_settings.dock_brightness, Some(60));
         assert_eq!(loaded_settings.auto_update, true);
 
         // Clean up
@@ -181,4 +420,10 @@ mod tests {
         assert_eq!(settings.dpi_x, 3200);
         assert_eq!(settings.polling_rate, 1000);
     }
+
+    #[test]
+    fn test_legacy_single_brightness_applies_to_all_zones() {
+        let settings: MouseSettings = serde_json::from_str(r#"{ "brightness": 50 }"#).unwrap();
+        assert_eq!(settings.brightness, uniform_brightness(50));
+    }
 }
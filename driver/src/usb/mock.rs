@@ -0,0 +1,91 @@
+use std::os::raw::c_void;
+use std::time::Duration;
+use razer::{RazerReport, RAZER_CMD_SUCCESSFUL};
+use crate::{Device, DriverError, DriverResult, UsbDriver};
+
+type Responder = Box<dyn FnMut(&RazerReport) -> Vec<u8>>;
+
+/// Transport for tests that records the reports sent to it and answers them without hardware.
+pub(crate) struct MockUsbDriver {
+    /// Requests in the order they were sent.
+    pub sent: Vec<RazerReport>,
+    respond: Responder,
+}
+
+impl MockUsbDriver {
+    /// Answers every request with a successful echo of it.
+    pub fn echoing() -> Self {
+        Self::answering(|request| response_bytes(*request, RAZER_CMD_SUCCESSFUL))
+    }
+
+    /// Answers every request with the bytes `respond` returns for it.
+    pub fn answering(respond: impl FnMut(&RazerReport) -> Vec<u8> + 'static) -> Self {
+        Self { sent: vec![], respond: Box::new(respond) }
+    }
+}
+
+/// Wire bytes of `report` as the device would answer it with `status`.
+pub(crate) fn response_bytes(mut report: RazerReport, status: u8) -> Vec<u8> {
+    report.finalize();
+    let mut bytes = report.to_hid_bytes();
+    bytes[0] = status;
+    bytes
+}
+
+impl UsbDriver for MockUsbDriver {
+    unsafe fn new(_vendor_id: u16, _product_id: u16) -> DriverResult<Self> {
+        Ok(Self::echoing())
+    }
+
+    unsafe fn list_devices() -> Vec<Device> {
+        vec![]
+    }
+
+    unsafe fn send_control_msg(
+        &mut self,
+        _request: u8,
+        _value: u16,
+        _index: u16,
+        _data: &[u8],
+        _min_wait: Duration,
+    ) -> DriverResult<()> {
+        Ok(())
+    }
+
+    unsafe fn get_feature_report(
+        &mut self,
+        data: &[u8],
+        _index: u16,
+        _min_wait: Duration,
+        _response_length: u16,
+    ) -> DriverResult<Vec<u8>> {
+        let request = RazerReport::try_from(data)?;
+        self.sent.push(request);
+        Ok((self.respond)(&request))
+    }
+
+    unsafe fn close(&mut self) -> DriverResult<()> {
+        Ok(())
+    }
+
+    fn on_device_connected<F>(_vendor_id: u16, _product_id: u16, _callback: F) -> DriverResult<()>
+    where
+        F: FnMut(&Device) + Send + 'static,
+    {
+        Ok(())
+    }
+
+    fn on_device_disconnected<F>(_vendor_id: u16, _product_id: u16, _callback: F) -> DriverResult<()>
+    where
+        F: FnMut(&Device) + Send + 'static,
+    {
+        Ok(())
+    }
+
+    fn on_state_changed<F>(&mut self, _callback: F) -> DriverResult<()>
+    where
+        F: FnMut(&Device, &mut c_void) + Send + 'static,
+    {
+        Err(DriverError::NotImplemented("State changes of the mock transport".into()))
+    }
+}
@@ -1,4 +1,6 @@
 mod native;
+#[cfg(test)]
+pub(crate) mod mock;
 
 #[cfg(target_os = "macos")]
 mod usb_impl {
@@ -11,4 +13,4 @@ mod usb_impl {
 }
 
 pub use usb_impl::PlatformUsbDriver;
-pub use native::UsbDriver;
\ No newline at end of file
+pub use native::{Device, UsbDriver};
\ No newline at end of file
@@ -14,8 +14,11 @@ use crate::{DriverError, DriverResult};
 use bindings::{__errno_location, ioctl};
 
 // ioctl macros and constants for hidraw
-// These are calculated for 91 bytes: 1 byte Report ID + 90 bytes Razer Report
-const HIDIOCSFEATURE: u64 = 0xC05B4806; // _IOWR('H', 0x06, 91)
+// The size is encoded in the request: 91 bytes for 1 byte Report ID + 90 bytes Razer Report,
+// 320 bytes for an ARGB report that carries its own Report ID
+const fn hidiocsfeature(len: usize) -> u64 {
+    0xC0004806 | ((len as u64) << 16) // _IOWR('H', 0x06, len)
+}
 const HIDIOCGFEATURE: u64 = 0xC05B4807; // _IOWR('H', 0x07, 91)
 
 type HotplugCallback = Box<dyn FnMut(&Device) + Send + 'static>;
@@ -39,18 +42,18 @@ fn get_registry() -> &'static Mutex<HotplugRegistry> {
 pub struct LinuxUsbDriver {
     file: fs::File,
     _path: PathBuf,
-    _vendor_id: u16,
-    _product_id: u16,
+    vendor_id: u16,
+    product_id: u16,
 }
 
 unsafe impl Send for LinuxUsbDriver {}
 unsafe impl Sync for LinuxUsbDriver {}
 
 impl LinuxUsbDriver {
-    fn find_hidraw_device(vendor_id: u16, product_id: u16) -> Result<PathBuf, String> {
+    fn find_hidraw_device(vendor_id: u16, product_id: u16, interface: u16) -> Result<PathBuf, String> {
         let entries = fs::read_dir("/sys/class/hidraw").map_err(|e| e.to_string())?;
 
-        let mut interface_0 = None;
+        let mut interface_path = None;
 
         for entry in entries {
             let entry = entry.map_err(|e| e.to_string())?;
@@ -66,32 +69,35 @@ impl LinuxUsbDriver {
                 {
                     let path = PathBuf::from("/dev").join(name);
 
-                    if uevent.contains("input0") || uevent.contains(":1.0") {
-                        interface_0 = Some(path);
-                        break; // We only care about Interface 0
+                    if uevent.contains(&format!("input{}", interface))
+                        || uevent.contains(&format!(":1.{}", interface))
+                    {
+                        interface_path = Some(path);
+                        break;
                     }
                 }
             }
         }
 
-        if let Some(path) = interface_0 {
+        if let Some(path) = interface_path {
             return Ok(path);
         }
 
         eprintln!(
-            "DRIVER ERROR: Hidraw device not found for VID:{:04X} PID:{:04X}",
-            vendor_id, product_id
+            "DRIVER ERROR: Hidraw device not found for VID:{:04X} PID:{:04X} interface {}",
+            vendor_id, product_id, interface
         );
         Err(format!(
-            "Hidraw device not found for VID:{:04X} PID:{:04X}",
-            vendor_id, product_id
+            "Hidraw device not found for VID:{:04X} PID:{:04X} interface {}",
+            vendor_id, product_id, interface
         ))
     }
 }
 
 impl UsbDriver for LinuxUsbDriver {
     unsafe fn new(vendor_id: u16, product_id: u16) -> DriverResult<Self> {
-        let path = match Self::find_hidraw_device(vendor_id, product_id) {
+        // Razer reports go to Interface 0
+        let path = match Self::find_hidraw_device(vendor_id, product_id, 0) {
             Ok(p) => p,
             Err(e) => {
                 eprintln!("DRIVER ERROR: find_hidraw_device failed: {}", e);
@@ -111,8 +117,8 @@ impl UsbDriver for LinuxUsbDriver {
         Ok(Self {
             file,
             _path: path,
-            _vendor_id: vendor_id,
-            _product_id: product_id,
+            vendor_id,
+            product_id,
         })
     }
 
@@ -151,6 +157,8 @@ impl UsbDriver for LinuxUsbDriver {
                                         name,
                                         vendor_id,
                                         product_id,
+                                        serial_number: None,
+                                        firmware_version: None,
                                     });
                                 }
                             }
@@ -166,19 +174,35 @@ impl UsbDriver for LinuxUsbDriver {
     unsafe fn send_control_msg(
         &mut self,
         _request: u8,
-        _value: u16,
-        _index: u16,
+        value: u16,
+        index: u16,
         data: &[u8],
         min_wait: Duration,
     ) -> DriverResult<()> {
-        let fd = self.file.as_raw_fd();
-
-        // HIDIOCSFEATURE(91) - including report ID
-        let mut buf = vec![0u8; data.len() + 1];
-        buf[0] = 0x00; // Report ID
-        buf[1..].copy_from_slice(data);
+        // Other interfaces get their own hidraw node, opened for this transfer only
+        let mut interface_file;
+        let file = if index == 0 {
+            &mut self.file
+        } else {
+            let path = Self::find_hidraw_device(self.vendor_id, self.product_id, index)
+                .map_err(DriverError::UsbError)?;
+            interface_file = fs::OpenOptions::new().read(true).write(true).open(&path)?;
+            &mut interface_file
+        };
+        let fd = file.as_raw_fd();
+
+        // Report ID 0 has to be prepended, numbered reports already start with their ID
+        let buf = match value & 0xFF {
+            0 => {
+                let mut buf = vec![0u8; data.len() + 1];
+                buf[0] = 0x00; // Report ID
+                buf[1..].copy_from_slice(data);
+                buf
+            }
+            _ => data.to_vec(),
+        };
 
-        let res = ioctl(fd, HIDIOCSFEATURE, buf.as_ptr());
+        let res = ioctl(fd, hidiocsfeature(buf.len()), buf.as_ptr());
 
         if res < 0 {
             let errno = *__errno_location();
@@ -187,7 +211,7 @@ impl UsbDriver for LinuxUsbDriver {
                 res, errno
             );
             // Fallback: simple write()
-            match self.file.write_all(&buf) {
+            match file.write_all(&buf) {
                 Ok(_) => {}
                 Err(e) => {
                     eprintln!("DRIVER ERROR: hidraw write fallback failed: {}", e);
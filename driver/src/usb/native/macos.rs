@@ -99,6 +99,8 @@ unsafe extern "C" fn device_notification_callback(
             name,
             vendor_id: vendor_id as u32,
             product_id: product_id as u32,
+            serial_number: None,
+            firmware_version: None,
         };
 
         // This is a bit tricky because we don't know if it's connected or disconnected here
@@ -393,6 +395,8 @@ impl UsbDriver for MacOsUsbDriver {
                 name: usb_device_name,
                 vendor_id,
                 product_id,
+                serial_number: None,
+                firmware_version: None,
             });
 
             IOObjectRelease(usb_device);
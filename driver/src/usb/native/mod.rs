@@ -1,5 +1,6 @@
 use std::os::raw::c_void;
 use std::time::Duration;
+use razer::FirmwareVersion;
 use crate::DriverResult;
 
 #[cfg(target_os = "macos")]
@@ -14,6 +15,9 @@ pub struct Device {
     pub name: String,
     pub vendor_id: u32,
     pub product_id: u32,
+    /// Only known for supported Razer devices that answered, see `identify`.
+    pub serial_number: Option<String>,
+    pub firmware_version: Option<FirmwareVersion>,
 }
 
 pub trait UsbDriver {
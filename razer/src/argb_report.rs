@@ -1,3 +1,22 @@
+/* Colour frame for the Chroma Addressable RGB Controller.
+ *
+ * Unlike `RazerReport` this is not a command with a response, it is written as its own HID
+ * report (id 0x04, 0x84 for the sixth channel) and streams the colours of one channel.
+ *
+ * struct razer_argb_report {
+ *     unsigned char report_id;
+ *     unsigned char channel_1;
+ *     unsigned char channel_2;
+ *     unsigned char pad;
+ *     unsigned char last_idx;
+ *     unsigned char color_data[315];
+ * };
+ * */
+
+use crate::error::ReportError;
+use crate::RAZER_ARGB_REPORT_LEN;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RazerARGBReport {
     report_id: u8,
     channel_1: u8,
@@ -5,4 +24,68 @@ pub struct RazerARGBReport {
     pad: u8,
     last_idx: u8,
     color_data: [u8; 315],
-}
\ No newline at end of file
+}
+
+impl RazerARGBReport {
+    pub const CHANNELS: u8 = 6;
+    pub const MAX_LEDS: usize = 80;
+
+    pub fn new(channel: u8, colors: &[[u8; 3]]) -> Result<Self, ReportError> {
+        if channel >= Self::CHANNELS {
+            return Err(ReportError::InvalidArgument(format!("ARGB channel {} doesn't exist, the controller has {}", channel, Self::CHANNELS)));
+        }
+        if colors.is_empty() || colors.len() > Self::MAX_LEDS {
+            return Err(ReportError::InvalidArgument(format!("{} LEDs don't fit between 1 and {} per channel", colors.len(), Self::MAX_LEDS)));
+        }
+
+        let mut color_data = [0u8; 315];
+        for (i, rgb) in colors.iter().enumerate() {
+            color_data[i * 3..i * 3 + 3].copy_from_slice(rgb);
+        }
+
+        Ok(Self {
+            report_id: if channel < 5 { 0x04 } else { 0x84 },
+            channel_1: channel,
+            channel_2: channel,
+            pad: 0,
+            last_idx: (colors.len() - 1) as u8,
+            color_data,
+        })
+    }
+
+    pub fn report_id(&self) -> u8 {
+        self.report_id
+    }
+
+    pub fn to_bytes(&self) -> [u8; RAZER_ARGB_REPORT_LEN] {
+        let mut bytes = [0u8; RAZER_ARGB_REPORT_LEN];
+        bytes[0] = self.report_id;
+        bytes[1] = self.channel_1;
+        bytes[2] = self.channel_2;
+        bytes[3] = self.pad;
+        bytes[4] = self.last_idx;
+        bytes[5..].copy_from_slice(&self.color_data);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_argb_report_bytes() {
+        let bytes = RazerARGBReport::new(2, &[[1, 2, 3], [4, 5, 6]]).unwrap().to_bytes();
+        assert_eq!(bytes.len(), 320);
+        assert_eq!(bytes[..11], [0x04, 0x02, 0x02, 0x00, 0x01, 1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(RazerARGBReport::new(5, &[[0, 0, 0]]).unwrap().report_id(), 0x84);
+    }
+
+    #[test]
+    fn test_argb_report_validation() {
+        assert!(RazerARGBReport::new(6, &[[0, 0, 0]]).is_err());
+        assert!(RazerARGBReport::new(0, &[]).is_err());
+        assert!(RazerARGBReport::new(0, &[[0, 0, 0]; 81]).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use super::Command;
+use crate::error::ReportError;
+use crate::response::{DeviceMode, ExtendedPollingRate, FirmwareVersion, PollingRate, SerialNumber};
+
+// razer_chroma_standard_get_firmware_version
+pub struct GetFirmwareVersion;
+
+impl Command for GetFirmwareVersion {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x81;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = FirmwareVersion;
+}
+
+// razer_chroma_standard_get_serial
+pub struct GetSerialNumber;
+
+impl Command for GetSerialNumber {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x82;
+    const DATA_SIZE: u8 = 0x16;
+
+    type Response = SerialNumber;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1482
+pub struct GetPollingRate;
+
+impl Command for GetPollingRate {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x85;
+    const DATA_SIZE: u8 = 0x01;
+
+    type Response = PollingRate;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1630
+/**
+* Identifier is in arg[0]
+*
+* 1000 = 0x01
+* 500  = 0x02
+* 125  = 0x08
+*/
+pub struct SetPollingRate {
+    identifier: u8,
+}
+
+impl SetPollingRate {
+    pub fn new(polling_rate: u16) -> Result<Self, ReportError> {
+        let identifier = match polling_rate {
+            1000 => 0x01,
+            500 => 0x02,
+            125 => 0x08,
+            _ => return Err(ReportError::InvalidArgument(format!("Invalid polling rate: {}. Must be 1000, 500 or 125", polling_rate))),
+        };
+
+        Ok(Self { identifier })
+    }
+}
+
+impl Command for SetPollingRate {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x05;
+    const DATA_SIZE: u8 = 0x01;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = self.identifier;
+        arguments
+    }
+}
+
+// razer_chroma_misc_get_polling_rate2
+pub struct GetExtendedPollingRate;
+
+impl Command for GetExtendedPollingRate {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0xC0;
+    const DATA_SIZE: u8 = 0x01;
+
+    type Response = ExtendedPollingRate;
+}
+
+// razer_chroma_misc_set_polling_rate2
+/**
+* arg[0] is 0x00, identifier in arg[1], see `ExtendedPollingRate`
+*/
+pub struct SetExtendedPollingRate {
+    identifier: u8,
+}
+
+impl SetExtendedPollingRate {
+    pub fn new(polling_rate: u16) -> Result<Self, ReportError> {
+        let (_, identifier) = ExtendedPollingRate::IDENTIFIERS
+            .iter()
+            .find(|(rate, _)| *rate == polling_rate)
+            .ok_or_else(|| ReportError::InvalidArgument(format!("Invalid polling rate: {}. Must be 125, 250, 500, 1000, 2000, 4000 or 8000", polling_rate)))?;
+
+        Ok(Self { identifier: *identifier })
+    }
+}
+
+impl Command for SetExtendedPollingRate {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x40;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[1] = self.identifier;
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L3040
+pub struct GetDeviceMode;
+
+impl Command for GetDeviceMode {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x84;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = DeviceMode;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2942
+/**
+* mode in arg[0], param in arg[1] (always 0)
+*/
+pub struct SetDeviceMode {
+    pub mode: DeviceMode,
+}
+
+impl Command for SetDeviceMode {
+    const COMMAND_CLASS: u8 = 0x00;
+    const COMMAND_ID: u8 = 0x04;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = self.mode as u8;
+        arguments
+    }
+}
@@ -0,0 +1,112 @@
+use super::Command;
+use crate::error::ReportError;
+use crate::report::DpiStage;
+use crate::response::{DpiStages, DpiXy};
+use crate::{RAZER_MOUSE_MAX_DPI_STAGES, VARSTORE};
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2055
+pub struct GetDpiXy;
+
+impl Command for GetDpiXy {
+    const COMMAND_CLASS: u8 = 0x04;
+    const COMMAND_ID: u8 = 0x85;
+    const DATA_SIZE: u8 = 0x07;
+
+    type Response = DpiXy;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1873
+pub struct SetDpiXy {
+    dpi_x: u16,
+    dpi_y: u16,
+}
+
+impl SetDpiXy {
+    pub fn new(dpi_x: u16, dpi_y: u16) -> Result<Self, ReportError> {
+        if !(100..=35000).contains(&dpi_x) {
+            return Err(ReportError::InvalidArgument(format!("dpi_x {} doesn't fit between 100 and 35000", dpi_x)));
+        }
+        if !(100..=35000).contains(&dpi_y) {
+            return Err(ReportError::InvalidArgument(format!("dpi_y {} doesn't fit between 100 and 35000", dpi_y)));
+        }
+
+        Ok(Self { dpi_x, dpi_y })
+    }
+}
+
+impl Command for SetDpiXy {
+    const COMMAND_CLASS: u8 = 0x04;
+    const COMMAND_ID: u8 = 0x05;
+    const DATA_SIZE: u8 = 0x07;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1..3].copy_from_slice(&self.dpi_x.to_be_bytes());
+        arguments[3..5].copy_from_slice(&self.dpi_y.to_be_bytes());
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2510
+pub struct GetDpiStages;
+
+impl Command for GetDpiStages {
+    const COMMAND_CLASS: u8 = 0x04;
+    const COMMAND_ID: u8 = 0x86;
+    const DATA_SIZE: u8 = 0x26;
+
+    type Response = DpiStages;
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2400
+pub struct SetDpiStages {
+    active_stage: u8,
+    stages: Vec<DpiStage>,
+}
+
+impl SetDpiStages {
+    pub fn new(active_stage: u8, stages: Vec<DpiStage>) -> Result<Self, ReportError> {
+        if stages.is_empty() {
+            return Err(ReportError::InvalidArgument("No DPI stages provided".to_string()));
+        }
+        if stages.len() > RAZER_MOUSE_MAX_DPI_STAGES as usize {
+            return Err(ReportError::InvalidArgument(format!("Too many DPI stages, max is {}", RAZER_MOUSE_MAX_DPI_STAGES)));
+        }
+
+        Ok(Self { active_stage, stages })
+    }
+}
+
+impl Command for SetDpiStages {
+    const COMMAND_CLASS: u8 = 0x04;
+    const COMMAND_ID: u8 = 0x06;
+    const DATA_SIZE: u8 = 0x26;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = self.active_stage;
+        arguments[2] = self.stages.len() as u8;
+
+        // Each stage: stage number, DPI X, DPI Y, 2 reserved bytes
+        for (i, stage) in self.stages.iter().enumerate() {
+            let offset = 3 + i * 7;
+            arguments[offset] = i as u8;
+            arguments[offset + 1..offset + 3].copy_from_slice(&stage.dpi_x.to_be_bytes());
+            arguments[offset + 3..offset + 5].copy_from_slice(&stage.dpi_y.to_be_bytes());
+        }
+
+        arguments
+    }
+}
@@ -0,0 +1,184 @@
+/* Standard LED commands (class 0x03) used by older devices.
+ *
+ * Newer devices such as the Basilisk V3 Pro use the extended matrix commands in `lighting` instead,
+ * `DeviceDescriptor::lighting` tells which set a device understands.
+ * */
+
+use super::Command;
+use crate::response::{LedBrightness, LedEffect, LedRgb, LedState};
+use crate::{RazerClassicEffectId, OFF, ON, VARSTORE};
+
+fn led_arguments(led_id: u8) -> [u8; 80] {
+    let mut arguments = [0u8; 80];
+    arguments[0] = VARSTORE;
+    arguments[1] = led_id;
+    arguments
+}
+
+// struct razer_report razer_chroma_standard_set_led_state(unsigned char variable_storage, unsigned char led_id, unsigned char led_state)
+//      struct razer_report report = get_razer_report(0x03, 0x00, 0x03);
+//      report.arguments[0] = variable_storage;
+//      report.arguments[1] = led_id;
+//      report.arguments[2] = clamp_u8(led_state, 0x00, 0x01);
+pub struct SetLedState {
+    pub led_id: u8,
+    pub on: bool,
+}
+
+impl Command for SetLedState {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x00;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = led_arguments(self.led_id);
+        arguments[2] = if self.on { ON } else { OFF };
+        arguments
+    }
+}
+
+// struct razer_report razer_chroma_standard_get_led_state(unsigned char variable_storage, unsigned char led_id)
+//      struct razer_report report = get_razer_report(0x03, 0x80, 0x03);
+pub struct GetLedState {
+    pub led_id: u8,
+}
+
+impl Command for GetLedState {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x80;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = LedState;
+
+    fn arguments(&self) -> [u8; 80] {
+        led_arguments(self.led_id)
+    }
+}
+
+// struct razer_report razer_chroma_standard_set_led_rgb(unsigned char variable_storage, unsigned char led_id, struct razer_rgb *rgb1)
+//      struct razer_report report = get_razer_report(0x03, 0x01, 0x05);
+//      report.arguments[2] = rgb1->r;
+//      report.arguments[3] = rgb1->g;
+//      report.arguments[4] = rgb1->b;
+pub struct SetLedRgb {
+    pub led_id: u8,
+    pub rgb: [u8; 3],
+}
+
+impl Command for SetLedRgb {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x01;
+    const DATA_SIZE: u8 = 0x05;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = led_arguments(self.led_id);
+        arguments[2..5].copy_from_slice(&self.rgb);
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c#L134C21-L134C54
+
+// struct razer_report razer_chroma_standard_get_led_rgb(unsigned char variable_storage, unsigned char led_id)
+//      struct razer_report report = get_razer_report(0x03, 0x81, 0x05);
+//      report.arguments[0] = variable_storage;
+//      report.arguments[1] = led_id;
+pub struct GetLedRgb {
+    pub led_id: u8,
+}
+
+impl Command for GetLedRgb {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x81;
+    const DATA_SIZE: u8 = 0x05;
+
+    type Response = LedRgb;
+
+    fn arguments(&self) -> [u8; 80] {
+        led_arguments(self.led_id)
+    }
+}
+
+// struct razer_report razer_chroma_standard_set_led_effect(unsigned char variable_storage, unsigned char led_id, unsigned char led_effect)
+//      struct razer_report report = get_razer_report(0x03, 0x02, 0x03);
+//      report.arguments[2] = clamp_u8(led_effect, 0x00, 0x05);
+pub struct SetLedEffect {
+    pub led_id: u8,
+    pub effect: RazerClassicEffectId,
+}
+
+impl Command for SetLedEffect {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x02;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = led_arguments(self.led_id);
+        arguments[2] = self.effect as u8;
+        arguments
+    }
+}
+
+// struct razer_report razer_chroma_standard_get_led_effect(unsigned char variable_storage, unsigned char led_id)
+//      struct razer_report report = get_razer_report(0x03, 0x82, 0x03);
+pub struct GetLedEffect {
+    pub led_id: u8,
+}
+
+impl Command for GetLedEffect {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x82;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = LedEffect;
+
+    fn arguments(&self) -> [u8; 80] {
+        led_arguments(self.led_id)
+    }
+}
+
+// struct razer_report razer_chroma_standard_set_led_brightness(unsigned char variable_storage, unsigned char led_id, unsigned char brightness)
+//      struct razer_report report = get_razer_report(0x03, 0x03, 0x03);
+//      report.arguments[2] = brightness;
+pub struct SetStandardLedBrightness {
+    pub led_id: u8,
+    pub brightness: u8,
+}
+
+impl Command for SetStandardLedBrightness {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x03;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = led_arguments(self.led_id);
+        arguments[2] = self.brightness;
+        arguments
+    }
+}
+
+// struct razer_report razer_chroma_standard_get_led_brightness(unsigned char variable_storage, unsigned char led_id)
+//      struct razer_report report = get_razer_report(0x03, 0x83, 0x03);
+pub struct GetStandardLedBrightness {
+    pub led_id: u8,
+}
+
+impl Command for GetStandardLedBrightness {
+    const COMMAND_CLASS: u8 = 0x03;
+    const COMMAND_ID: u8 = 0x83;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = LedBrightness;
+
+    fn arguments(&self) -> [u8; 80] {
+        led_arguments(self.led_id)
+    }
+}
@@ -0,0 +1,279 @@
+use super::Command;
+use crate::error::ReportError;
+use crate::response::LedBrightness;
+use crate::{RazerExtendedMatrixEffectId, NOSTORE, VARSTORE, ZERO_LED};
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c#L731
+// razer_chroma_extended_matrix_get_brightness
+pub struct GetLedBrightness {
+    pub led_id: u8,
+}
+
+impl Command for GetLedBrightness {
+    const COMMAND_CLASS: u8 = 0x0F;
+    const COMMAND_ID: u8 = 0x84;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = LedBrightness;
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = self.led_id;
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L4143
+/**
+* Note from devs:
+* // For old-school led commands
+* // matrix_brightness should mostly be called backlight_led_brightness (but it's too much work now for old devices)
+*
+* ZERO_LED addresses every zone at once, any other led id only that zone.
+*/
+pub struct SetLedBrightness {
+    pub led_id: u8,
+    pub brightness: u8,
+}
+
+impl Command for SetLedBrightness {
+    const COMMAND_CLASS: u8 = 0x0F;
+    const COMMAND_ID: u8 = 0x04;
+    const DATA_SIZE: u8 = 0x03;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = self.led_id;
+        arguments[2] = self.brightness;
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L3820
+
+// static ssize_t razer_attr_write_matrix_effect_static_common(struct device *dev, struct device_attribute *attr, const char *buf, size_t count, unsigned char led_id)
+//      request = razer_chroma_extended_matrix_effect_static(VARSTORE, led_id, (struct razer_rgb*)&buf[0]);
+//      request.transaction_id.id = 0x1f;
+
+// struct razer_report razer_chroma_extended_matrix_effect_static(unsigned char variable_storage, unsigned char led_id, struct razer_rgb *rgb)
+//      struct razer_report report = razer_chroma_extended_matrix_effect_base(0x09, variable_storage, led_id, 0x01);
+//      report.arguments[5] = 0x01;
+//      report.arguments[6] = rgb->r;
+//      report.arguments[7] = rgb->g;
+//      report.arguments[8] = rgb->b;
+
+// static struct razer_report razer_chroma_extended_matrix_effect_base(unsigned char arg_size, unsigned char variable_storage, unsigned char led_id, unsigned char effect_id)
+//      struct razer_report report = get_razer_report(0x0F, 0x02, arg_size);
+//      report.arguments[0] = variable_storage;
+//      report.arguments[1] = led_id;
+//      report.arguments[2] = effect_id;
+
+/**
+* The other effects share the same base, the arg_size grows with the number of colours:
+* breathing  arg[3] colour count, arg[5] colour count, colours from arg[6]
+* starlight  arg[4] speed (1-3), arg[5] colour count, colours from arg[6]
+* reactive   arg[4] speed (1-4), arg[5] = 0x01, colour in arg[6..9]
+* wave       arg[3] direction, arg[4] speed (openrazer always sends 0x28)
+*
+* struct razer_report razer_chroma_extended_matrix_effect_custom_frame(void)
+*      return razer_chroma_extended_matrix_effect_base(0x0C, NOSTORE, ZERO_LED, 0x08);
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EffectColors {
+    Random,
+    Single([u8; 3]),
+    Dual([u8; 3], [u8; 3]),
+}
+
+impl EffectColors {
+    fn count(&self) -> u8 {
+        match self {
+            EffectColors::Random => 0,
+            EffectColors::Single(_) => 1,
+            EffectColors::Dual(_, _) => 2,
+        }
+    }
+
+    fn write(&self, arguments: &mut [u8; 80]) {
+        arguments[5] = self.count();
+        match self {
+            EffectColors::Random => {}
+            EffectColors::Single(rgb) => arguments[6..9].copy_from_slice(rgb),
+            EffectColors::Dual(first, second) => {
+                arguments[6..9].copy_from_slice(first);
+                arguments[9..12].copy_from_slice(second);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WaveDirection {
+    Forward = 0x01,
+    Reverse = 0x02,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixEffect {
+    None,
+    Static([u8; 3]),
+    Breathing(EffectColors),
+    Spectrum,
+    Wave { direction: WaveDirection, speed: u8 },
+    Reactive { speed: u8, rgb: [u8; 3] },
+    Starlight { speed: u8, colors: EffectColors },
+    /// Shows the frame uploaded with `SetCustomFrame`.
+    CustomFrame,
+}
+
+impl MatrixEffect {
+    pub fn id(&self) -> RazerExtendedMatrixEffectId {
+        match self {
+            MatrixEffect::None => RazerExtendedMatrixEffectId::ExtendedMatrixEffectNone,
+            MatrixEffect::Static(_) => RazerExtendedMatrixEffectId::ExtendedMatrixEffectStatic,
+            MatrixEffect::Breathing(_) => RazerExtendedMatrixEffectId::ExtendedMatrixEffectBreathing,
+            MatrixEffect::Spectrum => RazerExtendedMatrixEffectId::ExtendedMatrixEffectSpectrum,
+            MatrixEffect::Wave { .. } => RazerExtendedMatrixEffectId::ExtendedMatrixEffectWave,
+            MatrixEffect::Reactive { .. } => RazerExtendedMatrixEffectId::ExtendedMatrixEffectReactive,
+            MatrixEffect::Starlight { .. } => RazerExtendedMatrixEffectId::ExtendedMatrixEffectStarlight,
+            MatrixEffect::CustomFrame => RazerExtendedMatrixEffectId::ExtendedMatrixEffectCustomFrame,
+        }
+    }
+}
+
+pub struct SetMatrixEffect {
+    led_id: u8,
+    effect: MatrixEffect,
+}
+
+impl SetMatrixEffect {
+    pub fn new(led_id: u8, effect: MatrixEffect) -> Result<Self, ReportError> {
+        match effect {
+            MatrixEffect::Wave { speed: 0, .. } => {
+                return Err(ReportError::InvalidArgument("wave speed must not be 0".to_string()));
+            }
+            MatrixEffect::Reactive { speed, .. } if !(1..=4).contains(&speed) => {
+                return Err(ReportError::InvalidArgument(format!("reactive speed {} doesn't fit between 1 and 4", speed)));
+            }
+            MatrixEffect::Starlight { speed, .. } if !(1..=3).contains(&speed) => {
+                return Err(ReportError::InvalidArgument(format!("starlight speed {} doesn't fit between 1 and 3", speed)));
+            }
+            _ => {}
+        }
+
+        Ok(Self { led_id, effect })
+    }
+}
+
+impl Command for SetMatrixEffect {
+    const COMMAND_CLASS: u8 = 0x0F;
+    const COMMAND_ID: u8 = 0x02;
+    const DATA_SIZE: u8 = 0x0C;
+
+    type Response = ();
+
+    fn data_size(&self) -> u8 {
+        match self.effect {
+            MatrixEffect::Static(_) | MatrixEffect::Reactive { .. } => 0x09,
+            MatrixEffect::Breathing(colors) | MatrixEffect::Starlight { colors, .. } => 0x06 + colors.count() * 3,
+            MatrixEffect::None | MatrixEffect::Spectrum | MatrixEffect::Wave { .. } => 0x06,
+            MatrixEffect::CustomFrame => 0x0C,
+        }
+    }
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = self.led_id;
+        arguments[2] = self.effect.id() as u8;
+
+        match self.effect {
+            MatrixEffect::None | MatrixEffect::Spectrum => {}
+            MatrixEffect::CustomFrame => {
+                arguments[0] = NOSTORE;
+                arguments[1] = ZERO_LED;
+            }
+            MatrixEffect::Static(rgb) => EffectColors::Single(rgb).write(&mut arguments),
+            MatrixEffect::Breathing(colors) => {
+                arguments[3] = colors.count();
+                colors.write(&mut arguments);
+            }
+            MatrixEffect::Wave { direction, speed } => {
+                arguments[3] = direction as u8;
+                arguments[4] = speed;
+            }
+            MatrixEffect::Reactive { speed, rgb } => {
+                arguments[4] = speed;
+                EffectColors::Single(rgb).write(&mut arguments);
+            }
+            MatrixEffect::Starlight { speed, colors } => {
+                arguments[4] = speed;
+                colors.write(&mut arguments);
+            }
+        }
+        arguments
+    }
+}
+
+// struct razer_report razer_chroma_extended_matrix_set_custom_frame2(unsigned char row_index, unsigned char start_col, unsigned char stop_col, unsigned char *rgb_data, size_t packetLength)
+//      const size_t row_length = (size_t) (((stop_col + 1) - start_col) * 3);
+//      struct razer_report report = get_razer_report(0x0F, 0x03, packetLength ? packetLength : row_length + 5);
+//      report.arguments[2] = row_index;
+//      report.arguments[3] = start_col;
+//      report.arguments[4] = stop_col;
+//      memcpy(&report.arguments[5], rgb_data, row_length);
+pub struct SetCustomFrame {
+    row: u8,
+    start_column: u8,
+    rgb: Vec<[u8; 3]>,
+}
+
+impl SetCustomFrame {
+    /// One report fits 25 LEDs after the five header bytes.
+    pub const MAX_COLUMNS: usize = 25;
+
+    pub fn new(row: u8, start_column: u8, rgb: Vec<[u8; 3]>) -> Result<Self, ReportError> {
+        if rgb.is_empty() {
+            return Err(ReportError::InvalidArgument("No LED colours provided".to_string()));
+        }
+        if rgb.len() > Self::MAX_COLUMNS {
+            return Err(ReportError::InvalidArgument(format!("{} LEDs don't fit in one frame row, at most {} do", rgb.len(), Self::MAX_COLUMNS)));
+        }
+        if start_column as usize + rgb.len() > u8::MAX as usize + 1 {
+            return Err(ReportError::InvalidArgument(format!("Columns starting at {} overflow the matrix", start_column)));
+        }
+
+        Ok(Self { row, start_column, rgb })
+    }
+
+    pub fn end_column(&self) -> u8 {
+        self.start_column + (self.rgb.len() - 1) as u8
+    }
+}
+
+impl Command for SetCustomFrame {
+    const COMMAND_CLASS: u8 = 0x0F;
+    const COMMAND_ID: u8 = 0x03;
+    const DATA_SIZE: u8 = 0x50;
+
+    type Response = ();
+
+    fn data_size(&self) -> u8 {
+        (self.rgb.len() * 3 + 5) as u8
+    }
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[2] = self.row;
+        arguments[3] = self.start_column;
+        arguments[4] = self.end_column();
+        for (i, rgb) in self.rgb.iter().enumerate() {
+            arguments[5 + i * 3..8 + i * 3].copy_from_slice(rgb);
+        }
+        arguments
+    }
+}
@@ -0,0 +1,149 @@
+/* A command pairs the report sent to the device with the type its response decodes into.
+ *
+ * Getters set the direction bit of the command id (0x80) and mostly send no arguments,
+ * setters answer with an echo of the request, so their response is `()`.
+ *
+ * `DATA_SIZE` is the payload length of the report; commands whose length depends on
+ * their arguments override `data_size`.
+ * */
+
+mod device;
+mod dpi;
+mod led;
+mod lighting;
+mod power;
+mod scroll;
+
+pub use device::*;
+pub use dpi::*;
+pub use led::*;
+pub use lighting::*;
+pub use power::*;
+pub use scroll::*;
+
+use crate::report::RazerReport;
+use crate::response::FromReport;
+
+pub trait Command {
+    const COMMAND_CLASS: u8;
+    const COMMAND_ID: u8;
+    const DATA_SIZE: u8;
+
+    type Response: FromReport;
+
+    fn arguments(&self) -> [u8; 80] {
+        [0; 80]
+    }
+
+    fn data_size(&self) -> u8 {
+        Self::DATA_SIZE
+    }
+
+    fn report(&self) -> RazerReport {
+        RazerReport::new(Self::COMMAND_CLASS, Self::COMMAND_ID, self.data_size(), self.arguments())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::DpiStage;
+    use crate::{RazerClassicEffectId, ReportError};
+
+    #[test]
+    fn test_getter_report_header() {
+        let report = GetDpiXy.report();
+        assert_eq!(report.command_class(), 0x04);
+        assert_eq!(report.command_id().raw(), 0x85);
+        assert_eq!(report.command_id().direction(), 1);
+        assert_eq!(report.data_size(), 0x07);
+    }
+
+    #[test]
+    fn test_set_dpi_xy_arguments() {
+        let arguments = SetDpiXy::new(3200, 1600).unwrap().arguments();
+        assert_eq!(arguments[..7], [0x01, 0x0C, 0x80, 0x06, 0x40, 0x00, 0x00]);
+
+        assert!(matches!(SetDpiXy::new(50, 1600), Err(ReportError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_set_dpi_stages_arguments() {
+        let stages = vec![
+            DpiStage { dpi_x: 800, dpi_y: 800, stage: 1 },
+            DpiStage { dpi_x: 1800, dpi_y: 1800, stage: 2 },
+        ];
+        let arguments = SetDpiStages::new(2, stages).unwrap().arguments();
+
+        assert_eq!(
+            arguments[..17],
+            [
+                0x01, 0x02, 0x02,
+                0x00, 0x03, 0x20, 0x03, 0x20, 0x00, 0x00,
+                0x01, 0x07, 0x08, 0x07, 0x08, 0x00, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_polling_rate_rejects_unknown_rate() {
+        assert_eq!(SetPollingRate::new(500).unwrap().arguments()[0], 0x02);
+        assert!(SetPollingRate::new(250).is_err());
+    }
+
+    #[test]
+    fn test_led_brightness_arguments() {
+        let set = SetLedBrightness { led_id: crate::SCROLL_WHEEL_LED, brightness: 40 };
+        assert_eq!(set.arguments()[..3], [0x01, 0x01, 40]);
+
+        let get = GetLedBrightness { led_id: crate::LOGO_LED }.report();
+        assert_eq!(get.command_id().raw(), 0x84);
+        assert_eq!(get.arguments[..2], [0x01, 0x04]);
+    }
+
+    #[test]
+    fn test_set_matrix_effect_arguments() {
+        let static_effect = SetMatrixEffect::new(crate::ZERO_LED, MatrixEffect::Static([1, 2, 3])).unwrap();
+        assert_eq!(static_effect.report().data_size(), 0x09);
+        assert_eq!(static_effect.arguments()[..9], [0x01, 0x00, 0x01, 0x00, 0x00, 0x01, 1, 2, 3]);
+
+        let breathing = SetMatrixEffect::new(crate::LOGO_LED, MatrixEffect::Breathing(EffectColors::Dual([1, 2, 3], [4, 5, 6]))).unwrap();
+        assert_eq!(breathing.report().data_size(), 0x0C);
+        assert_eq!(breathing.arguments()[..12], [0x01, 0x04, 0x02, 0x02, 0x00, 0x02, 1, 2, 3, 4, 5, 6]);
+
+        let wave = SetMatrixEffect::new(crate::ZERO_LED, MatrixEffect::Wave { direction: WaveDirection::Reverse, speed: 0x28 }).unwrap();
+        assert_eq!(wave.report().data_size(), 0x06);
+        assert_eq!(wave.arguments()[..5], [0x01, 0x00, 0x04, 0x02, 0x28]);
+
+        let starlight = MatrixEffect::Starlight { speed: 4, colors: EffectColors::Random };
+        assert!(matches!(SetMatrixEffect::new(crate::ZERO_LED, starlight), Err(ReportError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_set_custom_frame_arguments() {
+        let frame = SetCustomFrame::new(0, 2, vec![[1, 2, 3], [4, 5, 6]]).unwrap();
+        assert_eq!(frame.report().data_size(), 11);
+        assert_eq!(frame.arguments()[..11], [0x00, 0x00, 0x00, 0x02, 0x03, 1, 2, 3, 4, 5, 6]);
+
+        let activate = SetMatrixEffect::new(crate::BACKLIGHT_LED, MatrixEffect::CustomFrame).unwrap();
+        assert_eq!(activate.report().data_size(), 0x0C);
+        assert_eq!(activate.arguments()[..3], [0x00, 0x00, 0x08]);
+
+        assert!(SetCustomFrame::new(0, 0, vec![]).is_err());
+        assert!(SetCustomFrame::new(0, 0, vec![[0, 0, 0]; 26]).is_err());
+    }
+
+    #[test]
+    fn test_standard_led_arguments() {
+        let state = SetLedState { led_id: crate::LOGO_LED, on: true }.report();
+        assert_eq!((state.command_class(), state.command_id().raw()), (0x03, 0x00));
+        assert_eq!(state.arguments[..3], [0x01, 0x04, 0x01]);
+
+        let effect = SetLedEffect { led_id: crate::BACKLIGHT_LED, effect: RazerClassicEffectId::ClassicEffectSpectrum };
+        assert_eq!(effect.arguments()[..3], [0x01, 0x05, 0x04]);
+
+        let rgb = SetLedRgb { led_id: crate::SCROLL_WHEEL_LED, rgb: [1, 2, 3] }.report();
+        assert_eq!(rgb.data_size(), 0x05);
+        assert_eq!(rgb.arguments[..5], [0x01, 0x01, 1, 2, 3]);
+    }
+}
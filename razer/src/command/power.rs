@@ -0,0 +1,118 @@
+use super::Command;
+use crate::error::ReportError;
+use crate::response::{raw_from_percentage, BatteryLevel, ChargingState, IdleTimeout, LowBatteryThreshold};
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1257
+pub struct GetBatteryLevel;
+
+impl Command for GetBatteryLevel {
+    const COMMAND_CLASS: u8 = 0x07;
+    const COMMAND_ID: u8 = 0x80;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = BatteryLevel;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1331
+pub struct GetChargingState;
+
+impl Command for GetChargingState {
+    const COMMAND_CLASS: u8 = 0x07;
+    const COMMAND_ID: u8 = 0x84;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ChargingState;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2610
+pub struct GetIdleTimeout;
+
+impl Command for GetIdleTimeout {
+    const COMMAND_CLASS: u8 = 0x07;
+    const COMMAND_ID: u8 = 0x83;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = IdleTimeout;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2667
+
+// struct razer_report razer_chroma_misc_set_idle_time(unsigned short idle_time)
+//      struct razer_report report = get_razer_report(0x07, 0x03, 0x02);
+//      // Need to store as ushort in the reports array
+//      report.arguments[0] = (idle_time >> 8) & 0x00FF;
+//      report.arguments[1] = idle_time & 0x00FF;
+pub struct SetIdleTimeout {
+    seconds: u16,
+}
+
+impl SetIdleTimeout {
+    pub fn new(seconds: u16) -> Result<Self, ReportError> {
+        // openrazer refuses values outside 1 to 15 minutes
+        if !(60..=900).contains(&seconds) {
+            return Err(ReportError::InvalidArgument(format!("idle timeout {}s doesn't fit between 60 and 900", seconds)));
+        }
+
+        Ok(Self { seconds })
+    }
+}
+
+impl Command for SetIdleTimeout {
+    const COMMAND_CLASS: u8 = 0x07;
+    const COMMAND_ID: u8 = 0x03;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0..2].copy_from_slice(&self.seconds.to_be_bytes());
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2721
+pub struct GetChargeLowThreshold;
+
+impl Command for GetChargeLowThreshold {
+    const COMMAND_CLASS: u8 = 0x07;
+    const COMMAND_ID: u8 = 0x81;
+    const DATA_SIZE: u8 = 0x01;
+
+    type Response = LowBatteryThreshold;
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2766
+
+// struct razer_report razer_chroma_misc_set_low_battery_threshold(unsigned char battery_threshold)
+//      // Near as I can tell, threshold is between 0x0C (~5%) and 0x3F (~25%)
+//      battery_threshold = clamp_u8(battery_threshold, 0x0C, 0x3F);
+//      struct razer_report report = get_razer_report(0x07, 0x01, 0x01);
+//      report.arguments[0] = battery_threshold;
+pub struct SetChargeLowThreshold {
+    raw: u8,
+}
+
+impl SetChargeLowThreshold {
+    pub fn from_percentage(percentage: u8) -> Result<Self, ReportError> {
+        if !(5..=25).contains(&percentage) {
+            return Err(ReportError::InvalidArgument(format!("low battery threshold {}% doesn't fit between 5 and 25", percentage)));
+        }
+
+        Ok(Self { raw: raw_from_percentage(percentage).clamp(0x0C, 0x3F) })
+    }
+}
+
+impl Command for SetChargeLowThreshold {
+    const COMMAND_CLASS: u8 = 0x07;
+    const COMMAND_ID: u8 = 0x01;
+    const DATA_SIZE: u8 = 0x01;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = self.raw;
+        arguments
+    }
+}
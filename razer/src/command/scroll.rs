@@ -0,0 +1,134 @@
+use super::Command;
+use crate::response::{ScrollAccelerationState, ScrollMode, SmartReelState};
+use crate::{OFF, ON, VARSTORE};
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2311
+pub struct GetScrollSmartReel;
+
+impl Command for GetScrollSmartReel {
+    const COMMAND_CLASS: u8 = 0x02;
+    const COMMAND_ID: u8 = 0x97;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = SmartReelState;
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2288
+
+// struct razer_report razer_chroma_misc_set_scroll_smart_reel(unsigned char enabled)
+//      struct razer_report report = get_razer_report(0x02, 0x17, 0x02);
+//      report.arguments[0] = VARSTORE;
+//      report.arguments[1] = enabled;
+pub struct SetScrollSmartReel {
+    pub enabled: bool,
+}
+
+impl Command for SetScrollSmartReel {
+    const COMMAND_CLASS: u8 = 0x02;
+    const COMMAND_ID: u8 = 0x17;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = if self.enabled { ON } else { OFF };
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2269
+
+// struct razer_report razer_chroma_misc_get_scroll_acceleration(void)
+//      struct razer_report report = get_razer_report(0x02, 0x96, 0x02);
+//      report.arguments[0] = VARSTORE;
+pub struct GetScrollAcceleration;
+
+impl Command for GetScrollAcceleration {
+    const COMMAND_CLASS: u8 = 0x02;
+    const COMMAND_ID: u8 = 0x96;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ScrollAccelerationState;
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2246
+
+// struct razer_report razer_chroma_misc_set_scroll_acceleration(unsigned char enabled)
+//      struct razer_report report = get_razer_report(0x02, 0x16, 0x02);
+//      report.arguments[0] = VARSTORE;
+//      report.arguments[1] = enabled;
+pub struct SetScrollAcceleration {
+    pub enabled: bool,
+}
+
+impl Command for SetScrollAcceleration {
+    const COMMAND_CLASS: u8 = 0x02;
+    const COMMAND_ID: u8 = 0x16;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = if self.enabled { ON } else { OFF };
+        arguments
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c
+// razer_chroma_misc_get_scroll_mode
+//      struct razer_report report = get_razer_report(0x02, 0x94, 0x02);
+//      report.arguments[0] = VARSTORE;
+pub struct GetScrollMode;
+
+impl Command for GetScrollMode {
+    const COMMAND_CLASS: u8 = 0x02;
+    const COMMAND_ID: u8 = 0x94;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ScrollMode;
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments
+    }
+}
+
+// razer_chroma_misc_set_scroll_mode
+//      struct razer_report report = get_razer_report(0x02, 0x14, 0x02);
+//      report.arguments[0] = VARSTORE;
+//      report.arguments[1] = mode;
+pub struct SetScrollMode {
+    pub mode: ScrollMode,
+}
+
+impl Command for SetScrollMode {
+    const COMMAND_CLASS: u8 = 0x02;
+    const COMMAND_ID: u8 = 0x14;
+    const DATA_SIZE: u8 = 0x02;
+
+    type Response = ();
+
+    fn arguments(&self) -> [u8; 80] {
+        let mut arguments = [0u8; 80];
+        arguments[0] = VARSTORE;
+        arguments[1] = self.mode as u8;
+        arguments
+    }
+}
@@ -1,15 +1,22 @@
-const OFF: u16 = 0x00;
-const ON : u16 = 0x01;
+pub(crate) const OFF: u8 = 0x00;
+pub(crate) const ON : u8 = 0x01;
 
 // LED STORAGE Options
 pub const NOSTORE  : u8 =         0x00;
 pub const VARSTORE : u8 =         0x01;
 
 pub const RAZER_USB_VENDOR_ID: u16 = 0x1532;
+pub const RAZER_BASILISK_V3_PRO_WIRED_ID: u16 = 0x00AA;
 pub const RAZER_BASILISK_V3_PRO_ID: u16 = 0x00AB;
+pub const RAZER_DEATHADDER_CHROMA_ID: u16 = 0x0043;
+pub const RAZER_HYPERPOLLING_WIRELESS_DONGLE_ID: u16 = 0x00B3;
+pub const RAZER_CHROMA_ARGB_CONTROLLER_ID: u16 = 0x0F1F;
+pub const RAZER_MOUSE_DOCK_PRO_ID: u16 = 0x00A4;
 pub const RAZER_USB_REPORT_LEN: u8 =  0x5A;
+pub const RAZER_ARGB_REPORT_LEN: usize = 320;
 pub const RAZER_MOUSE_MAX_DPI_STAGES: u8 = 5;
 
+pub const RAZER_CMD_NEW          : u8 = 0x00;
 pub const RAZER_CMD_BUSY         : u8 = 0x01;
 pub const RAZER_CMD_SUCCESSFUL   : u8 = 0x02;
 pub const RAZER_CMD_FAILURE      : u8 = 0x03;
@@ -18,6 +25,7 @@ pub const RAZER_CMD_NOT_SUPPORTED: u8 = 0x05;
 
 pub const RAZER_NEW_MOUSE_RECEIVER_WAIT_MIN_US: u16 = 31000;
 pub const RAZER_NEW_MOUSE_RECEIVER_WAIT_MAX_US: u16 = 31100;
+pub const RAZER_ACCESSORY_WAIT_MIN_US: u16 = 600;
 
 // LED definitions
 pub const ZERO_LED         : u8 = 0x00;
@@ -42,14 +50,18 @@ pub const CHARGING_LED     : u8 = 0x20;
 pub const FAST_CHARGING_LED: u8 = 0x21;
 pub const FULLY_CHARGED_LED: u8 = 0x22;
 
-enum RazerClassicEffectId {
+// Effect ids of the standard LED commands (class 0x03)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RazerClassicEffectId {
     ClassicEffectStatic = 0x00,
     ClassicEffectBlinking = 0x01,
     ClassicEffectBreathing = 0x02, // also called pulsating
     ClassicEffectSpectrum = 0x04,
 }
 
-enum RazerMatrixEffectId {
+// Effect ids of the standard matrix (class 0x03)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RazerMatrixEffectId {
     MatrixEffectOff = 0x00,
     MatrixEffectWave = 0x01,
     MatrixEffectReactive = 0x02, // afterglow
@@ -59,3 +71,16 @@ enum RazerMatrixEffectId {
     MatrixEffectStatic = 0x06,
     MatrixEffectStarlight = 0x19,
 }
+
+// Effect ids of the extended matrix (class 0x0F), used by newer devices such as the Basilisk V3 Pro
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RazerExtendedMatrixEffectId {
+    ExtendedMatrixEffectNone = 0x00,
+    ExtendedMatrixEffectStatic = 0x01,
+    ExtendedMatrixEffectBreathing = 0x02,
+    ExtendedMatrixEffectSpectrum = 0x03,
+    ExtendedMatrixEffectWave = 0x04,
+    ExtendedMatrixEffectReactive = 0x05,
+    ExtendedMatrixEffectStarlight = 0x07,
+    ExtendedMatrixEffectCustomFrame = 0x08,
+}
@@ -0,0 +1,149 @@
+/* Per product protocol details and capabilities.
+ *
+ * The transaction id addresses the device behind a connection: openrazer uses 0x1f for most
+ * current mice, 0x3f for some wireless receivers and 0xff for older products.
+ *
+ * Older products drive their LEDs with the standard commands (class 0x03), newer ones with the
+ * extended matrix (class 0x0F). Likewise, HyperPolling capable products take their polling
+ * rate through the extended command (0x00/0x40), which goes beyond 1000Hz.
+ * */
+
+use crate::report::TransactionId;
+use crate::{
+    RAZER_BASILISK_V3_PRO_ID, RAZER_BASILISK_V3_PRO_WIRED_ID, RAZER_CHROMA_ARGB_CONTROLLER_ID,
+    RAZER_DEATHADDER_CHROMA_ID, RAZER_HYPERPOLLING_WIRELESS_DONGLE_ID, RAZER_MOUSE_DOCK_PRO_ID,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeviceKind {
+    Mouse,
+    /// Takes `RazerARGBReport` colour frames for its channels.
+    ArgbController,
+    /// Charges the mouse and has a lighting ring of its own.
+    Dock,
+    /// Wireless receiver, it only takes the polling rate of the mouse paired with it.
+    Receiver,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LightingProtocol {
+    /// The device has no LEDs.
+    None,
+    Standard,
+    Extended,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PollingProtocol {
+    Standard,
+    Extended,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DeviceDescriptor {
+    pub name: &'static str,
+    pub product_id: u16,
+    pub kind: DeviceKind,
+    pub transaction_id: TransactionId,
+    /// Whether the device accepts any id in the lower five bits, so every request can get its own.
+    /// Devices that only answer to their fixed id keep it for every request.
+    pub rolling_transaction_ids: bool,
+    pub lighting: LightingProtocol,
+    /// Size of the LED matrix addressed by custom frames, 0x0 if the device has none.
+    pub matrix_rows: u8,
+    pub matrix_columns: u8,
+    pub polling: PollingProtocol,
+    /// Polling rates in Hz the device accepts, empty if it has none to set.
+    pub polling_rates: &'static [u16],
+}
+
+pub const BASILISK_V3_PRO_WIRED: DeviceDescriptor = DeviceDescriptor {
+    name: "Razer Basilisk V3 Pro (Wired)",
+    product_id: RAZER_BASILISK_V3_PRO_WIRED_ID,
+    kind: DeviceKind::Mouse,
+    transaction_id: TransactionId::new(0, 0x1f),
+    rolling_transaction_ids: false,
+    lighting: LightingProtocol::Extended,
+    matrix_rows: 1,
+    matrix_columns: 13,
+    polling: PollingProtocol::Standard,
+    polling_rates: &[125, 500, 1000],
+};
+
+pub const BASILISK_V3_PRO_WIRELESS: DeviceDescriptor = DeviceDescriptor {
+    name: "Razer Basilisk V3 Pro (Wireless)",
+    product_id: RAZER_BASILISK_V3_PRO_ID,
+    kind: DeviceKind::Mouse,
+    transaction_id: TransactionId::new(0, 0x1f),
+    rolling_transaction_ids: false,
+    lighting: LightingProtocol::Extended,
+    matrix_rows: 1,
+    matrix_columns: 13,
+    polling: PollingProtocol::Standard,
+    polling_rates: &[125, 500, 1000],
+};
+
+pub const HYPERPOLLING_WIRELESS_DONGLE: DeviceDescriptor = DeviceDescriptor {
+    name: "Razer HyperPolling Wireless Dongle",
+    product_id: RAZER_HYPERPOLLING_WIRELESS_DONGLE_ID,
+    kind: DeviceKind::Receiver,
+    transaction_id: TransactionId::new(0, 0x1f),
+    rolling_transaction_ids: false,
+    lighting: LightingProtocol::None,
+    matrix_rows: 0,
+    matrix_columns: 0,
+    polling: PollingProtocol::Extended,
+    polling_rates: &[125, 250, 500, 1000, 2000, 4000, 8000],
+};
+
+pub const DEATHADDER_CHROMA: DeviceDescriptor = DeviceDescriptor {
+    name: "Razer DeathAdder Chroma",
+    product_id: RAZER_DEATHADDER_CHROMA_ID,
+    kind: DeviceKind::Mouse,
+    transaction_id: TransactionId::new(7, 0x1f),
+    rolling_transaction_ids: false,
+    lighting: LightingProtocol::Standard,
+    matrix_rows: 0,
+    matrix_columns: 0,
+    polling: PollingProtocol::Standard,
+    polling_rates: &[125, 500, 1000],
+};
+
+pub const CHROMA_ARGB_CONTROLLER: DeviceDescriptor = DeviceDescriptor {
+    name: "Razer Chroma Addressable RGB Controller",
+    product_id: RAZER_CHROMA_ARGB_CONTROLLER_ID,
+    kind: DeviceKind::ArgbController,
+    transaction_id: TransactionId::new(1, 0x1f),
+    rolling_transaction_ids: false,
+    lighting: LightingProtocol::Extended,
+    matrix_rows: 0,
+    matrix_columns: 0,
+    polling: PollingProtocol::Standard,
+    polling_rates: &[],
+};
+
+pub const MOUSE_DOCK_PRO: DeviceDescriptor = DeviceDescriptor {
+    name: "Razer Mouse Dock Pro",
+    product_id: RAZER_MOUSE_DOCK_PRO_ID,
+    kind: DeviceKind::Dock,
+    transaction_id: TransactionId::new(0, 0x1f),
+    rolling_transaction_ids: false,
+    lighting: LightingProtocol::Extended,
+    matrix_rows: 0,
+    matrix_columns: 0,
+    polling: PollingProtocol::Standard,
+    polling_rates: &[],
+};
+
+pub const SUPPORTED_DEVICES: &[DeviceDescriptor] = &[
+    BASILISK_V3_PRO_WIRED,
+    BASILISK_V3_PRO_WIRELESS,
+    HYPERPOLLING_WIRELESS_DONGLE,
+    DEATHADDER_CHROMA,
+    CHROMA_ARGB_CONTROLLER,
+    MOUSE_DOCK_PRO,
+];
+
+pub fn find_device(product_id: u16) -> Option<&'static DeviceDescriptor> {
+    SUPPORTED_DEVICES.iter().find(|device| device.product_id == product_id)
+}
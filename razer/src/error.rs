@@ -0,0 +1,44 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportError {
+    InvalidLength(usize, usize),
+    CrcMismatch(u8, u8),
+    ResponseMismatch(&'static str, u8, u8),
+    Busy,
+    CommandFailure,
+    CommandTimeout,
+    NotSupported,
+    UnexpectedStatus(u8),
+    UnknownValue(&'static str, u8),
+    InvalidArgument(String),
+    InvalidPayload(String),
+    PacketSequence(u16, u16),
+}
+
+impl fmt::Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportError::InvalidLength(expected, actual) => write!(f, "Invalid report length: expected {} bytes, got {}", expected, actual),
+            ReportError::CrcMismatch(expected, actual) => write!(f, "CRC mismatch: expected 0x{:02x}, got 0x{:02x}", expected, actual),
+            ReportError::ResponseMismatch(field, expected, actual) => write!(f, "Response does not match request: expected {} 0x{:02x}, got 0x{:02x}", field, expected, actual),
+            ReportError::Busy => write!(f, "Device busy"),
+            ReportError::CommandFailure => write!(f, "Device reported a command failure"),
+            ReportError::CommandTimeout => write!(f, "Device did not respond"),
+            ReportError::NotSupported => write!(f, "Command not supported by device"),
+            ReportError::UnexpectedStatus(status) => write!(f, "Unexpected command status: 0x{:02x}", status),
+            ReportError::UnknownValue(field, v) => write!(f, "Unknown {}: 0x{:02x}", field, v),
+            ReportError::InvalidArgument(e) => write!(f, "Invalid argument: {}", e),
+            ReportError::InvalidPayload(e) => write!(f, "Invalid payload: {}", e),
+            ReportError::PacketSequence(expected, actual) => write!(f, "Report missing from transfer: expected {} remaining packets, got {}", expected, actual),
+        }
+    }
+}
+
+impl std::error::Error for ReportError {}
+
+impl From<ReportError> for String {
+    fn from(e: ReportError) -> Self {
+        e.to_string()
+    }
+}
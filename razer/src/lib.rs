@@ -1,8 +1,19 @@
 mod report;
+mod command;
+mod response;
 mod consts;
 mod argb_report;
-// mod devices;
+mod transfer;
+mod error;
+mod devices;
 
 pub use report::RazerReport;
+pub use report::{CommandId, CommandStatus, TransactionId};
 pub use report::DpiStage;
+pub use response::*;
+pub use command::*;
+pub use argb_report::RazerARGBReport;
+pub use transfer::{join_payload, split_payload, PayloadAssembler};
+pub use error::ReportError;
+pub use devices::*;
 pub use consts::*;
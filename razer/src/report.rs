@@ -18,23 +18,71 @@
  * */
 
 use crate::consts::RAZER_USB_REPORT_LEN;
-use crate::{NOSTORE, RAZER_MOUSE_MAX_DPI_STAGES, VARSTORE, ZERO_LED};
+use crate::error::ReportError;
+use crate::{
+    RAZER_CMD_BUSY, RAZER_CMD_FAILURE, RAZER_CMD_NEW, RAZER_CMD_NOT_SUPPORTED,
+    RAZER_CMD_SUCCESSFUL, RAZER_CMD_TIMEOUT,
+};
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct DpiStage {
     pub dpi_x: u16,
     pub dpi_y: u16,
     pub stage: u8,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CommandStatus {
+    New,
+    Busy,
+    Successful,
+    Failure,
+    Timeout,
+    NotSupported,
+    Unknown(u8),
+}
+
+impl From<u8> for CommandStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            RAZER_CMD_NEW => CommandStatus::New,
+            RAZER_CMD_BUSY => CommandStatus::Busy,
+            RAZER_CMD_SUCCESSFUL => CommandStatus::Successful,
+            RAZER_CMD_FAILURE => CommandStatus::Failure,
+            RAZER_CMD_TIMEOUT => CommandStatus::Timeout,
+            RAZER_CMD_NOT_SUPPORTED => CommandStatus::NotSupported,
+            other => CommandStatus::Unknown(other),
+        }
+    }
+}
+
+impl CommandStatus {
+    pub fn raw(&self) -> u8 {
+        match self {
+            CommandStatus::New => RAZER_CMD_NEW,
+            CommandStatus::Busy => RAZER_CMD_BUSY,
+            CommandStatus::Successful => RAZER_CMD_SUCCESSFUL,
+            CommandStatus::Failure => RAZER_CMD_FAILURE,
+            CommandStatus::Timeout => RAZER_CMD_TIMEOUT,
+            CommandStatus::NotSupported => RAZER_CMD_NOT_SUPPORTED,
+            CommandStatus::Unknown(raw) => *raw,
+        }
+    }
+}
+
+/* struct transaction_id_type {
+ *     unsigned char id:5;
+ *     unsigned char device:3;
+ * };
+ * */
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct TransactionId(u8);
 
 impl TransactionId {
-    pub fn new(device: u8, id: u8) -> Self {
+    pub const fn new(device: u8, id: u8) -> Self {
         assert!(device < 8);
         assert!(id < 32);
-        Self((device & 0b0000_0111) | ((id & 0b0001_1111) << 3))
+        Self(((device & 0b0000_0111) << 5) | (id & 0b0001_1111))
     }
 
     pub fn raw(&self) -> u8 {
@@ -42,11 +90,16 @@ impl TransactionId {
     }
 
     pub fn device(&self) -> u8 {
-        self.0 & 0b0000_0111
+        (self.0 >> 5) & 0b0000_0111
     }
 
     pub fn id(&self) -> u8 {
-        (self.0 >> 3) & 0b0001_1111
+        self.0 & 0b0001_1111
+    }
+
+    /// The next id for the same device, wrapping around after 31.
+    pub fn next(&self) -> Self {
+        Self::new(self.device(), (self.id() + 1) & 0b0001_1111)
     }
 }
 
@@ -73,7 +126,6 @@ impl CommandId {
     }
 }
 
-#[repr(C, packed)]
 #[derive(Copy, Clone, Debug)]
 pub struct RazerReport {
     status: u8,
@@ -88,563 +140,154 @@ pub struct RazerReport {
     reserved: u8, /*0x0*/
 }
 
-impl RazerReport {
-    pub unsafe fn from_bytes(bytes: &[u8]) -> Self {
-        if bytes.len() != RAZER_USB_REPORT_LEN as usize {
-            panic!("Invalid length: expected {} bytes, got {}", RAZER_USB_REPORT_LEN, bytes.len());
-        }
-
-        // Cast raw bytes to RazerReport
-        let mut full_data = [0u8; 90];
-        full_data.copy_from_slice(bytes);
-
-        let report: RazerReport = unsafe {
-            std::ptr::read_unaligned(full_data.as_ptr() as *const RazerReport)
-        };
-
-        report
-    }
-
-    fn raw_bytes(&self) -> [u8; 88] {
-        let mut list: [u8; 88] = [0; 88];
-        list[0] = self.status;
-        list[1] = self.transaction_id.0;
+impl TryFrom<&[u8]> for RazerReport {
+    type Error = ReportError;
 
-        let be =  self.remaining_packets.to_be_bytes();
-        list[2] = be[0];
-        list[3] = be[1];
-        list[4] = self.protocol_type;
-        list[5] = self.data_size;
-        list[6] = self.command_class;
-        list[7] = self.command_id.0;
-        list[8..88].copy_from_slice(&self.arguments);
-        
-        list
-    }
-
-    pub fn to_hid_bytes(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(90);
-
-        buf.extend_from_slice(self.raw_bytes().as_ref());
-        buf.push(self.crc);
-        buf.push(self.reserved);
-        
-        buf
-    }
-
-    pub fn finalize(&mut self) {
-        let bytes = self.raw_bytes();
-        self.crc = bytes[2..88].iter().fold(0u8, |acc, &b| acc ^ b);
-    }
-
-    pub fn get_firmware_report() -> Self {
-        let mut args = [0u8; 80];
-        args[0] = 0x00;
-        args[1] = 0x00;
-
-        Self {
-            status: 0x0,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x02,
-            command_class: 0x00,
-            command_id: CommandId(0x81),
-            arguments: args,
-            crc: 0x00,
-            reserved: 0x00,
-        }
-    }
-
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1482
-    /**
-    * Get the polling rate from the device
-    *
-    * Identifier is in arg[0]
-    *
-    * 0x01 = 1000Hz
-    * 0x02 =  500Hz
-    * 0x08 =  125Hz
-    */
-    pub fn get_poll_rate_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x01,
-            command_class: 0x00,
-            command_id: CommandId(0x85),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != RAZER_USB_REPORT_LEN as usize {
+            return Err(ReportError::InvalidLength(RAZER_USB_REPORT_LEN as usize, bytes.len()));
         }
-    }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1630
-    /**
-    * Set the polling rate for the device
-    *
-    * Identifier is in arg[0]
-    *
-    * 1000 = 0x01
-    * 500  = 0x02 (50Hz)
-    * 125  = 0x08
-    */
-    pub fn set_poll_rate_report(polling_rate: u16) -> Result<Self, String> {
         let mut arguments = [0u8; 80];
-        arguments[0] = match polling_rate {
-            1000 => 0x01,
-            500 => 0x02,
-            125 => 0x08,
-            _ => return Err(format!("Invalid polling rate: {}. Must be 1000, 500 or 125", polling_rate)),
-        };
-
-        Ok(Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x01,
-            command_class: 0x00,
-            command_id: CommandId(0x05),
+        arguments.copy_from_slice(&bytes[8..88]);
+
+        let report = Self {
+            status: bytes[0],
+            transaction_id: TransactionId(bytes[1]),
+            remaining_packets: u16::from_be_bytes([bytes[2], bytes[3]]),
+            protocol_type: bytes[4],
+            data_size: bytes[5],
+            command_class: bytes[6],
+            command_id: CommandId(bytes[7]),
             arguments,
-            crc: 0x00,
-            reserved: 0x00,
-        })
-    }
+            crc: bytes[88],
+            reserved: bytes[89],
+        };
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2055
-    /**
-    * let dpi_x: u16 = ((response.arguments[1] as u16) << 8) | (response.arguments[2] as u16 & 0xFF);
-    * let dpi_y: u16 = ((response.arguments[3] as u16) << 8) | (response.arguments[4] as u16 & 0xFF);
-    */
-    pub fn get_dpi_xy_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x07,
-            command_class: 0x04,
-            command_id: CommandId(0x85),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
+        let expected_crc = report.checksum();
+        if report.crc != expected_crc {
+            return Err(ReportError::CrcMismatch(expected_crc, report.crc));
         }
-    }
-
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1873
-    pub fn set_dpi_xy_report(dpi_x: u16, dpi_y: u16) -> Self {
-        // verify that dpi_x and dpi_y are in the range of 100-35000
-        assert!(dpi_x >= 100 && dpi_x <= 35000, "dpi_x doesn't fit between 100 and 35000");
-        assert!(dpi_y >= 100 && dpi_y <= 35000, "dpi_y doesn't fit between 100 and 35000");
 
-        // c-like
-        // report.arguments[1] = (dpi_x >> 8) & 0x00FF;
-        // report.arguments[2] = dpi_x & 0x00FF;
-        // report.arguments[3] = (dpi_y >> 8) & 0x00FF;
-        // report.arguments[4] = dpi_y & 0x00FF;
-
-        let mut arguments = [0u8; 80];
-        arguments[0] = VARSTORE;
-        arguments[1] = ((dpi_x >> 8) & 0x00FF) as u8;
-        arguments[2] = (dpi_x & 0x00FF) as u8;
-        arguments[3] = ((dpi_y >> 8) & 0x00FF) as u8;
-        arguments[4] = (dpi_y & 0x00FF) as u8;
-        arguments[5] = 0x00;
-        arguments[6] = 0x00;
+        Ok(report)
+    }
+}
 
+impl RazerReport {
+    pub(crate) fn new(command_class: u8, command_id: u8, data_size: u8, arguments: [u8; 80]) -> Self {
         Self {
             status: 0x00,
             transaction_id: TransactionId(0x1f),
             remaining_packets: 0x00,
             protocol_type: 0x00,
-            data_size: 0x07,
-            command_class: 0x04,
-            command_id: CommandId(0x05),
+            data_size,
+            command_class,
+            command_id: CommandId(command_id),
             arguments,
             crc: 0x00,
             reserved: 0x00,
         }
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1331
-    pub fn get_charging_state_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x02,
-            command_class: 0x07,
-            command_id: CommandId(0x84),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
-        }
+    pub fn status(&self) -> CommandStatus {
+        CommandStatus::from(self.status)
     }
-    
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1257
-    /**
-    * Gets battery level
-    *
-    * 0->255 is in arg[1]
-    * Returns an integer which needs to be scaled from 0-255 -> 0-100
-    */
-    pub fn get_battery_level_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x02,
-            command_class: 0x07,
-            command_id: CommandId(0x80),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
-        }
-    }
-
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2115
-    /**
-    * Response: Brightness is at arg[0] for dock and arg[1] for led_brightness (where does this come from?: https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2134)
-    * same as get_led_brightness_report(led_id: u8)
-    * Note from devs:
-    * // For old-school led commands
-    * // matrix_brightness should mostly be called backlight_led_brightness (but it's too much work now for old devices)
-    */
-    pub fn get_matrix_brightness_report() -> Self {
-        let mut arguments = [0u8; 80];
-        arguments[0] = VARSTORE;
-        arguments[1] = ZERO_LED;
 
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x03,
-            command_class: 0x0F,
-            command_id: CommandId(0x84),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
+    /// Turns every status except "successful" into an error.
+    ///
+    /// A response with a failure status still echoes the request, so this has to be checked
+    /// in addition to `validate_response` before its arguments can be trusted.
+    pub fn check_status(&self) -> Result<(), ReportError> {
+        match self.status() {
+            CommandStatus::Successful => Ok(()),
+            CommandStatus::Busy => Err(ReportError::Busy),
+            CommandStatus::Failure => Err(ReportError::CommandFailure),
+            CommandStatus::Timeout => Err(ReportError::CommandTimeout),
+            CommandStatus::NotSupported => Err(ReportError::NotSupported),
+            other => Err(ReportError::UnexpectedStatus(other.raw())),
         }
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1756
-    pub fn set_matrix_brightness_report(brightness: u8) -> Self {
-        let mut arguments = [0u8; 80];
-        
-        arguments[0] = VARSTORE;
-        arguments[1] = ZERO_LED;
-        arguments[2] = brightness;
-        
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x03,
-            command_class: 0x0F,
-            command_id: CommandId(0x04),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
-        }
+    pub fn transaction_id(&self) -> TransactionId {
+        self.transaction_id
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c#L731
-    // razer_chroma_extended_matrix_get_brightness
-    // same as get_matrix_brightness_report, but with led_id
-    pub fn get_led_brightness_report(led_id: u8) -> Self {
-        let mut arguments = [0u8; 80];
-        arguments[0] = 0x01;
-        arguments[1] = led_id;
-
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x03,
-            command_class: 0x0F,
-            command_id: CommandId(0x84),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
-        }
+    pub fn set_transaction_id(&mut self, transaction_id: TransactionId) {
+        self.transaction_id = transaction_id;
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L4143
-    fn set_led_brightness_report() -> Self {
-        unimplemented!()
+    pub fn data_size(&self) -> u8 {
+        self.data_size
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2311
-    pub fn get_scroll_smart_reel_report() -> Self {
-        let mut arguments = [0u8; 80];
-        arguments[0] = 0x01;
-
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x02,
-            command_class: 0x02,
-            command_id: CommandId(0x97),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
-        }
+    /// Number of reports still to come in a multi-packet transfer, see `split_payload`.
+    pub fn remaining_packets(&self) -> u16 {
+        self.remaining_packets
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2288
-    fn set_scroll_smart_reel_report() -> Self {
-        unimplemented!()
+    pub(crate) fn set_remaining_packets(&mut self, remaining_packets: u16) {
+        self.remaining_packets = remaining_packets;
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2269
-    fn get_scroll_acceleration_report() -> Self {
-        unimplemented!()
+    pub fn command_class(&self) -> u8 {
+        self.command_class
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2246
-    fn set_scroll_acceleration_report() -> Self {
-        unimplemented!()
+    pub fn command_id(&self) -> CommandId {
+        self.command_id
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2510
-    /*
-    /**
-         * Read device file "dpi_stages"
-         *
-         * Writes the DPI stages array to buf.
-         *
-         * Each DPI stage is described by 4 bytes:
-         *   - 2 bytes (unsigned short) for x-axis DPI
-         *   - 2 bytes (unsigned short) for y-axis DPI
-         *
-         * Always writes 1+n*4 bytes:
-         *   - 1 byte: active DPI stage number, >= 0 and <= n.
-         *   - n*4 bytes: n DPI stages.
-         */
-
-            unsigned char stages_count;
-            ssize_t count;                 // bytes written
-            unsigned int i;                // iterator over stages_count
-            unsigned char *args;           // pointer to the next dpi value in response.arguments
-
-
-     */
-    pub fn get_dpi_stages_report() -> Self {
-        let mut arguments = [0u8; 80];
-        arguments[0] = VARSTORE;
-
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x26, // stages_count
-            command_class: 0x04,
-            command_id: CommandId(0x86),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
+    /// Checks that `self` is the device's answer to `request`.
+    ///
+    /// The device echoes command class, command id and transaction id of the request it answers,
+    /// so a mismatch means we read a stale response or one meant for another transaction.
+    pub fn validate_response(&self, request: &RazerReport) -> Result<(), ReportError> {
+        if self.command_class != request.command_class {
+            return Err(ReportError::ResponseMismatch("command class", request.command_class, self.command_class));
         }
-    }
-
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2400
-    pub fn set_dpi_stages_report(active_stage: u8, dpi_stages: Vec<DpiStage>) -> Self {
-        let stages_count = dpi_stages.len();
-        assert!(
-            stages_count <= RAZER_MOUSE_MAX_DPI_STAGES as usize,
-            "Too many DPI stages, max is {}",
-            RAZER_MOUSE_MAX_DPI_STAGES
-        );
-
-        let mut arguments = [0u8; 80];
-        arguments[0] = VARSTORE;
-        arguments[1] = active_stage;
-        arguments[2] = stages_count as u8;
-
-        let mut offset = 3;
-
-        for (i, stage) in dpi_stages.iter().enumerate() {
-            // Stage number
-            arguments[offset] = i as u8;
-            offset += 1;
-
-            // DPI X
-            arguments[offset] = (stage.dpi_x >> 8) as u8;
-            arguments[offset + 1] = (stage.dpi_x & 0xFF) as u8;
-            offset += 2;
-
-            // DPI Y
-            arguments[offset] = (stage.dpi_y >> 8) as u8;
-            arguments[offset + 1] = (stage.dpi_y & 0xFF) as u8;
-            offset += 2;
-
-            // Reserved
-            arguments[offset] = 0;
-            arguments[offset + 1] = 0;
-            offset += 2;
+        if self.command_id.0 != request.command_id.0 {
+            return Err(ReportError::ResponseMismatch("command id", request.command_id.0, self.command_id.0));
         }
-
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x26,
-            command_class: 0x04,
-            command_id: CommandId(0x06),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
+        if self.transaction_id.0 != request.transaction_id.0 {
+            return Err(ReportError::ResponseMismatch("transaction id", request.transaction_id.0, self.transaction_id.0));
         }
+        Ok(())
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2610
-    /**
-    * idle_time = (response.arguments[0] << 8) | (response.arguments[1] & 0xFF);
-    */
-    pub fn get_idle_timeout_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x02,
-            command_class: 0x07,
-            command_id: CommandId(0x83),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
-        }
-    }
+    fn raw_bytes(&self) -> [u8; 88] {
+        let mut list: [u8; 88] = [0; 88];
+        list[0] = self.status;
+        list[1] = self.transaction_id.0;
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2667
-    fn set_idle_timeout_report() -> Self {
-        unimplemented!()
+        let be =  self.remaining_packets.to_be_bytes();
+        list[2] = be[0];
+        list[3] = be[1];
+        list[4] = self.protocol_type;
+        list[5] = self.data_size;
+        list[6] = self.command_class;
+        list[7] = self.command_id.0;
+        list[8..88].copy_from_slice(&self.arguments);
+        
+        list
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2721
-    pub fn get_charge_low_threshold_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x01,
-            command_class: 0x07,
-            command_id: CommandId(0x81),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
-        }
-    }
+    pub fn to_hid_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(90);
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2766
-    fn set_charge_low_threshold_report() -> Self {
-        unimplemented!()
+        buf.extend_from_slice(self.raw_bytes().as_ref());
+        buf.push(self.crc);
+        buf.push(self.reserved);
+        
+        buf
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L3040
-    pub fn get_device_mode_report() -> Self {
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x02,
-            command_class: 0x00,
-            command_id: CommandId(0x84),
-            arguments: [0; 80],
-            crc: 0x00,
-            reserved: 0x00,
-        }
+    fn checksum(&self) -> u8 {
+        let bytes = self.raw_bytes();
+        bytes[2..88].iter().fold(0u8, |acc, &b| acc ^ b)
     }
 
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2942
-    fn set_device_mode_report() -> Self {
-        unimplemented!()
-    }
-    
-    
-    // https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L3820
-    
-    // static ssize_t razer_attr_write_matrix_effect_static_common(struct device *dev, struct device_attribute *attr, const char *buf, size_t count, unsigned char led_id)
-    //      request = razer_chroma_extended_matrix_effect_static(VARSTORE, led_id, (struct razer_rgb*)&buf[0]);
-    //      request.transaction_id.id = 0x1f;
-    
-    // struct razer_report razer_chroma_extended_matrix_effect_static(unsigned char variable_storage, unsigned char led_id, struct razer_rgb *rgb)
-    //      struct razer_report report = razer_chroma_extended_matrix_effect_base(0x09, variable_storage, led_id, 0x01);
-    //      report.arguments[5] = 0x01;
-    //      report.arguments[6] = rgb->r;
-    //      report.arguments[7] = rgb->g;
-    //      report.arguments[8] = rgb->b;
-    
-    // static struct razer_report razer_chroma_extended_matrix_effect_base(unsigned char arg_size, unsigned char variable_storage, unsigned char led_id, unsigned char effect_id)
-    //      struct razer_report report = get_razer_report(0x0F, 0x02, arg_size);
-    //      report.arguments[0] = variable_storage;
-    //      report.arguments[1] = led_id;
-    //      report.arguments[2] = effect_id;
-    
-    // struct razer_report get_razer_report(unsigned char command_class, unsigned char command_id, unsigned char data_size)
-    pub fn set_matrix_effect_static_report(rgb: [u8; 3], led_id: Option<u8>) -> Self {
-        let mut arguments = [0u8; 80];
-        arguments[0] = VARSTORE;
-        arguments[1] = led_id.unwrap_or(ZERO_LED);
-        arguments[2] = 0x01; // effect type -> static
-        
-        arguments[5] = 0x01;
-        arguments[6] = rgb[0];
-        arguments[7] = rgb[1];
-        arguments[8] = rgb[2];
-        
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x09,
-            command_class: 0x0F,
-            command_id: CommandId(0x02),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
-        }
-    }
-    
-    // https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c#L134C21-L134C54
-    
-    // struct razer_report razer_chroma_standard_get_led_rgb(unsigned char variable_storage, unsigned char led_id)
-    //      struct razer_report report = get_razer_report(0x03, 0x81, 0x05);
-    //      report.arguments[0] = variable_storage;
-    //      report.arguments[1] = led_id;
-    /// 
-    pub fn get_led_rgb_report(led_id: Option<u8>) -> Self {
-        let mut arguments = [0u8; 80];
-        arguments[0] = VARSTORE; // check if VARSTORE or NOSTORE
-        arguments[1] = led_id.unwrap_or(ZERO_LED);
-        
-        Self {
-            status: 0x00,
-            transaction_id: TransactionId(0x1f),
-            remaining_packets: 0x00,
-            protocol_type: 0x00,
-            data_size: 0x05,
-            command_class: 0x03,
-            command_id: CommandId(0x81),
-            arguments,
-            crc: 0x00,
-            reserved: 0x00,
-        }
+    pub fn finalize(&mut self) {
+        self.crc = self.checksum();
     }
 }
 
@@ -662,3 +305,97 @@ impl PartialEq<Self> for RazerReport {
             self.reserved == other.reserved
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::*;
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let mut request = GetDpiXy.report();
+        request.finalize();
+
+        let parsed = RazerReport::try_from(request.to_hid_bytes().as_slice()).unwrap();
+        assert_eq!(parsed, request);
+        assert!(parsed.validate_response(&request).is_ok());
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_length() {
+        let mut request = GetBatteryLevel.report();
+        request.finalize();
+        let bytes = request.to_hid_bytes();
+
+        assert_eq!(
+            RazerReport::try_from(&bytes[..40]),
+            Err(ReportError::InvalidLength(90, 40))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_crc() {
+        let mut request = GetBatteryLevel.report();
+        request.finalize();
+        let mut bytes = request.to_hid_bytes();
+        bytes[9] ^= 0xFF;
+
+        assert!(matches!(
+            RazerReport::try_from(bytes.as_slice()),
+            Err(ReportError::CrcMismatch(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_transaction_id_layout() {
+        assert_eq!(TransactionId::new(0, 0x1f).raw(), 0x1f);
+        assert_eq!(TransactionId::new(1, 0x1f).raw(), 0x3f);
+        assert_eq!(TransactionId::new(7, 0x1f).raw(), 0xff);
+
+        let next = TransactionId::new(1, 0x1f).next();
+        assert_eq!(next.device(), 1);
+        assert_eq!(next.id(), 0x00);
+    }
+
+    #[test]
+    fn test_validate_response_rejects_other_transaction() {
+        let mut request = GetBatteryLevel.report();
+        request.set_transaction_id(TransactionId::new(0, 0x03));
+        request.finalize();
+        let mut stale = GetBatteryLevel.report();
+        stale.set_transaction_id(TransactionId::new(0, 0x02));
+        stale.finalize();
+
+        assert_eq!(
+            stale.validate_response(&request),
+            Err(ReportError::ResponseMismatch("transaction id", 0x03, 0x02))
+        );
+    }
+
+    #[test]
+    fn test_check_status() {
+        let mut response = GetPollingRate.report();
+        assert_eq!(response.status(), CommandStatus::New);
+        assert_eq!(response.check_status(), Err(ReportError::UnexpectedStatus(0x00)));
+
+        response.status = RAZER_CMD_SUCCESSFUL;
+        assert!(response.check_status().is_ok());
+
+        response.status = RAZER_CMD_NOT_SUPPORTED;
+        assert_eq!(response.status(), CommandStatus::NotSupported);
+        assert_eq!(response.check_status(), Err(ReportError::NotSupported));
+    }
+
+    #[test]
+    fn test_validate_response_rejects_other_command() {
+        let mut request = GetBatteryLevel.report();
+        request.finalize();
+        let mut stale = GetChargingState.report();
+        stale.finalize();
+
+        assert_eq!(
+            stale.validate_response(&request),
+            Err(ReportError::ResponseMismatch("command id", 0x80, 0x84))
+        );
+    }
+}
@@ -0,0 +1,609 @@
+/* Typed views on the arguments of a received report.
+ *
+ * Offsets follow the read handlers in openrazer's razermouse_driver.c and razerchromacommon.c,
+ * so callers never have to pick bytes out of `RazerReport::arguments` themselves.
+ * */
+
+use std::fmt;
+
+use crate::error::ReportError;
+use crate::report::{DpiStage, RazerReport};
+use crate::{RazerClassicEffectId, ON};
+
+pub trait FromReport: Sized {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError>;
+}
+
+/// Setters only answer with an echo of the request.
+impl FromReport for () {
+    fn from_report(_report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(())
+    }
+}
+
+/// For commands whose payload has no typed decoder yet.
+impl FromReport for RazerReport {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(*report)
+    }
+}
+
+fn be_u16(high: u8, low: u8) -> u16 {
+    ((high as u16) << 8) | (low as u16)
+}
+
+/// Battery values are sent in the device range 0-255.
+/// Rounds like `raw_from_percentage`, so a percentage that is written reads back unchanged.
+fn percentage_from_raw(raw: u8) -> u8 {
+    (raw as f32 / 255f32 * 100f32).round() as u8
+}
+
+pub(crate) fn raw_from_percentage(percentage: u8) -> u8 {
+    (percentage.min(100) as f32 / 100f32 * 255f32).round() as u8
+}
+
+// razer_attr_read_firmware_version
+/**
+* Major version is in arg[0], minor version in arg[1]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FirmwareVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl FromReport for FirmwareVersion {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self {
+            major: report.arguments[0],
+            minor: report.arguments[1],
+        })
+    }
+}
+
+/// Formatted like Synapse and openrazer do, e.g. `v1.02`.
+impl fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}.{:02}", self.major, self.minor)
+    }
+}
+
+// razer_chroma_standard_get_serial
+/**
+* ASCII serial in arg[0..22], padded with zeros
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct SerialNumber {
+    pub serial: String,
+}
+
+impl FromReport for SerialNumber {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        let bytes = &report.arguments[..22];
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        Ok(Self {
+            serial: String::from_utf8_lossy(&bytes[..end]).trim().to_string(),
+        })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1257
+/**
+* 0->255 is in arg[1]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BatteryLevel {
+    pub raw: u8,
+}
+
+impl BatteryLevel {
+    /// Battery level scaled from the device range 0-255 to 0-100.
+    pub fn percentage(&self) -> u8 {
+        percentage_from_raw(self.raw)
+    }
+}
+
+impl FromReport for BatteryLevel {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self { raw: report.arguments[1] })
+    }
+}
+
+// razer_attr_read_charge_low_threshold
+/**
+* threshold in arg[0], same 0-255 range as the battery level
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LowBatteryThreshold {
+    pub raw: u8,
+}
+
+impl LowBatteryThreshold {
+    pub fn percentage(&self) -> u8 {
+        percentage_from_raw(self.raw)
+    }
+}
+
+impl FromReport for LowBatteryThreshold {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self { raw: report.arguments[0] })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1331
+/**
+* 0x00 or 0x01 in arg[1]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChargingState {
+    pub charging: bool,
+}
+
+impl FromReport for ChargingState {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self { charging: report.arguments[1] == 0x01 })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2610
+/**
+* idle_time = (response.arguments[0] << 8) | (response.arguments[1] & 0xFF);
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IdleTimeout {
+    pub seconds: u16,
+}
+
+impl FromReport for IdleTimeout {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self { seconds: be_u16(report.arguments[0], report.arguments[1]) })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L1482
+/**
+* Identifier is in arg[0]
+*
+* 0x01 = 1000Hz
+* 0x02 =  500Hz
+* 0x08 =  125Hz
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PollingRate(pub u16);
+
+impl FromReport for PollingRate {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        match report.arguments[0] {
+            0x01 => Ok(Self(1000)),
+            0x02 => Ok(Self(500)),
+            0x08 => Ok(Self(125)),
+            other => Err(ReportError::UnknownValue("polling rate", other)),
+        }
+    }
+}
+
+// razer_chroma_misc_get_polling_rate2
+/**
+* Identifier is in arg[1]
+*
+* 0x01 = 8000Hz
+* 0x02 = 4000Hz
+* 0x04 = 2000Hz
+* 0x08 = 1000Hz
+* 0x10 =  500Hz
+* 0x20 =  250Hz
+* 0x40 =  125Hz
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExtendedPollingRate(pub u16);
+
+impl ExtendedPollingRate {
+    pub(crate) const IDENTIFIERS: [(u16, u8); 7] = [
+        (8000, 0x01),
+        (4000, 0x02),
+        (2000, 0x04),
+        (1000, 0x08),
+        (500, 0x10),
+        (250, 0x20),
+        (125, 0x40),
+    ];
+}
+
+impl FromReport for ExtendedPollingRate {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Self::IDENTIFIERS
+            .iter()
+            .find(|(_, identifier)| *identifier == report.arguments[1])
+            .map(|(rate, _)| Self(*rate))
+            .ok_or(ReportError::UnknownValue("polling rate", report.arguments[1]))
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2055
+/**
+* DPI X is big endian in arg[1..3], DPI Y in arg[3..5]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DpiXy {
+    pub dpi_x: u16,
+    pub dpi_y: u16,
+}
+
+impl FromReport for DpiXy {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        let args = &report.arguments;
+        Ok(Self {
+            dpi_x: be_u16(args[1], args[2]),
+            dpi_y: be_u16(args[3], args[4]),
+        })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2510
+/**
+* 01    varstore
+* 02    active DPI stage
+* 04    number of stages
+*
+* followed by one 7 byte block per stage:
+* 01    stage number
+* 03 20 DPI X
+* 03 20 DPI Y
+* 00 00 reserved
+*
+* Stages are numbered from 1 in the order they are reported.
+*/
+#[derive(Debug, PartialEq)]
+pub struct DpiStages {
+    pub active_stage: u8,
+    pub stages: Vec<DpiStage>,
+}
+
+impl FromReport for DpiStages {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        let args = &report.arguments;
+        let active_stage = args[1];
+        let stages_count = args[2];
+        // Never read past the payload the device claims to have sent
+        let payload_end = (report.data_size() as usize).min(args.len());
+
+        let mut stages = Vec::with_capacity(stages_count as usize);
+        let mut offset = 4;
+
+        for stage in 1..=stages_count {
+            if offset + 4 > payload_end {
+                break;
+            }
+
+            stages.push(DpiStage {
+                dpi_x: be_u16(args[offset], args[offset + 1]),
+                dpi_y: be_u16(args[offset + 2], args[offset + 3]),
+                stage,
+            });
+
+            offset += 7;
+        }
+
+        Ok(Self { active_stage, stages })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c#L134
+/**
+* arg[0] varstore, arg[1] led id, RGB in arg[2..5]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LedRgb {
+    pub led_id: u8,
+    pub rgb: [u8; 3],
+}
+
+impl FromReport for LedRgb {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        let args = &report.arguments;
+        Ok(Self {
+            led_id: args[1],
+            rgb: [args[2], args[3], args[4]],
+        })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razerchromacommon.c#L731
+/**
+* arg[0] varstore, arg[1] led id, brightness in arg[2]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LedBrightness {
+    pub led_id: u8,
+    pub brightness: u8,
+}
+
+impl FromReport for LedBrightness {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self {
+            led_id: report.arguments[1],
+            brightness: report.arguments[2],
+        })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2311
+/**
+* arg[0] varstore, enabled flag in arg[1]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SmartReelState {
+    pub enabled: bool,
+}
+
+impl FromReport for SmartReelState {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self { enabled: report.arguments[1] == ON })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L2269
+/**
+* arg[0] varstore, enabled flag in arg[1]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScrollAccelerationState {
+    pub enabled: bool,
+}
+
+impl FromReport for ScrollAccelerationState {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self { enabled: report.arguments[1] == ON })
+    }
+}
+
+// https://github.com/openrazer/openrazer/blob/master/driver/razermouse_driver.c#L3040
+/**
+* mode in arg[0], arg[1] is always 0
+*
+* Normal mode leaves the buttons and lighting to the firmware,
+* in driver mode the host has to handle them.
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DeviceMode {
+    Normal = 0x00,
+    Factory = 0x02,
+    Driver = 0x03,
+}
+
+impl FromReport for DeviceMode {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        match report.arguments[0] {
+            0x00 => Ok(DeviceMode::Normal),
+            0x02 => Ok(DeviceMode::Factory),
+            0x03 => Ok(DeviceMode::Driver),
+            v => Err(ReportError::UnknownValue("device mode", v)),
+        }
+    }
+}
+
+// razer_chroma_misc_get_scroll_mode
+/**
+* arg[0] varstore, mode in arg[1]: 0 tactile (notched), 1 free spin
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ScrollMode {
+    Tactile = 0x00,
+    FreeSpin = 0x01,
+}
+
+impl FromReport for ScrollMode {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        match report.arguments[1] {
+            0x00 => Ok(ScrollMode::Tactile),
+            0x01 => Ok(ScrollMode::FreeSpin),
+            v => Err(ReportError::UnknownValue("scroll mode", v)),
+        }
+    }
+}
+
+// razer_chroma_standard_get_led_state
+/**
+* arg[0] varstore, arg[1] led id, state in arg[2]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LedState {
+    pub led_id: u8,
+    pub on: bool,
+}
+
+impl FromReport for LedState {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        Ok(Self {
+            led_id: report.arguments[1],
+            on: report.arguments[2] == ON,
+        })
+    }
+}
+
+// razer_chroma_standard_get_led_effect
+/**
+* arg[0] varstore, arg[1] led id, effect id in arg[2]
+*/
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LedEffect {
+    pub led_id: u8,
+    pub effect: RazerClassicEffectId,
+}
+
+impl FromReport for LedEffect {
+    fn from_report(report: &RazerReport) -> Result<Self, ReportError> {
+        let effect = match report.arguments[2] {
+            0x00 => RazerClassicEffectId::ClassicEffectStatic,
+            0x01 => RazerClassicEffectId::ClassicEffectBlinking,
+            0x02 => RazerClassicEffectId::ClassicEffectBreathing,
+            0x04 => RazerClassicEffectId::ClassicEffectSpectrum,
+            v => return Err(ReportError::UnknownValue("LED effect", v)),
+        };
+        Ok(Self { led_id: report.arguments[1], effect })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::*;
+
+    fn report_with(mut report: RazerReport, args: &[u8]) -> RazerReport {
+        report.arguments[..args.len()].copy_from_slice(args);
+        report
+    }
+
+    #[test]
+    fn test_decode_dpi_xy() {
+        let report = report_with(GetDpiXy.report(), &[0x01, 0x0C, 0x80, 0x06, 0x40]);
+        let dpi = DpiXy::from_report(&report).unwrap();
+        assert_eq!(dpi, DpiXy { dpi_x: 3200, dpi_y: 1600 });
+    }
+
+    #[test]
+    fn test_decode_battery_level() {
+        let report = report_with(GetBatteryLevel.report(), &[0x00, 0xFF]);
+        assert_eq!(BatteryLevel::from_report(&report).unwrap().percentage(), 100);
+
+        let report = report_with(GetBatteryLevel.report(), &[0x00, 0x80]);
+        assert_eq!(BatteryLevel::from_report(&report).unwrap().percentage(), 50);
+    }
+
+    #[test]
+    fn test_decode_polling_rate() {
+        let report = report_with(GetPollingRate.report(), &[0x02]);
+        assert_eq!(PollingRate::from_report(&report).unwrap(), PollingRate(500));
+
+        let report = report_with(GetPollingRate.report(), &[0x03]);
+        assert_eq!(
+            PollingRate::from_report(&report),
+            Err(ReportError::UnknownValue("polling rate", 0x03))
+        );
+    }
+
+    #[test]
+    fn test_decode_extended_polling_rate() {
+        let report = report_with(GetExtendedPollingRate.report(), &[0x00, 0x02]);
+        assert_eq!(ExtendedPollingRate::from_report(&report).unwrap(), ExtendedPollingRate(4000));
+
+        let set = SetExtendedPollingRate::new(8000).unwrap().report();
+        assert_eq!((set.command_id().raw(), set.arguments[..2].to_vec()), (0x40, vec![0x00, 0x01]));
+        assert!(SetExtendedPollingRate::new(3000).is_err());
+    }
+
+    #[test]
+    fn test_decode_dpi_stages() {
+        let report = report_with(
+            GetDpiStages.report(),
+            &[
+                0x01, 0x02, 0x02,
+                0x01, 0x03, 0x20, 0x03, 0x20, 0x00, 0x00,
+                0x02, 0x07, 0x08, 0x07, 0x08, 0x00, 0x00,
+            ],
+        );
+        let decoded = DpiStages::from_report(&report).unwrap();
+
+        assert_eq!(decoded.active_stage, 2);
+        assert_eq!(
+            decoded.stages,
+            vec![
+                DpiStage { dpi_x: 800, dpi_y: 800, stage: 1 },
+                DpiStage { dpi_x: 1800, dpi_y: 1800, stage: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_led_rgb() {
+        let report = report_with(GetLedRgb { led_id: 0x05 }.report(), &[0x01, 0x05, 0x10, 0x20, 0x30]);
+        let decoded = LedRgb::from_report(&report).unwrap();
+        assert_eq!(decoded, LedRgb { led_id: 0x05, rgb: [0x10, 0x20, 0x30] });
+    }
+
+    #[test]
+    fn test_decode_smart_reel() {
+        let report = report_with(GetScrollSmartReel.report(), &[0x01, 0x01]);
+        assert!(SmartReelState::from_report(&report).unwrap().enabled);
+
+        let set = SetScrollSmartReel { enabled: true }.report();
+        assert_eq!((set.command_id().raw(), set.arguments[1]), (0x17, 0x01));
+    }
+
+    #[test]
+    fn test_decode_scroll_acceleration() {
+        let report = report_with(GetScrollAcceleration.report(), &[0x01, 0x00]);
+        assert!(!ScrollAccelerationState::from_report(&report).unwrap().enabled);
+
+        let set = SetScrollAcceleration { enabled: true }.report();
+        assert_eq!((set.command_class(), set.command_id().raw(), set.arguments[1]), (0x02, 0x16, 0x01));
+    }
+
+    #[test]
+    fn test_decode_scroll_mode() {
+        let report = report_with(GetScrollMode.report(), &[0x01, 0x01]);
+        assert_eq!(ScrollMode::from_report(&report).unwrap(), ScrollMode::FreeSpin);
+
+        let report = report_with(GetScrollMode.report(), &[0x01, 0x02]);
+        assert_eq!(ScrollMode::from_report(&report), Err(ReportError::UnknownValue("scroll mode", 0x02)));
+
+        let set = SetScrollMode { mode: ScrollMode::FreeSpin }.report();
+        assert_eq!((set.command_id().raw(), set.arguments[..2].to_vec()), (0x14, vec![0x01, 0x01]));
+    }
+
+    #[test]
+    fn test_decode_firmware_and_serial() {
+        let report = report_with(GetFirmwareVersion.report(), &[0x01, 0x02]);
+        assert_eq!(FirmwareVersion::from_report(&report).unwrap().to_string(), "v1.02");
+
+        let report = report_with(GetSerialNumber.report(), b"PM2319H12345678");
+        assert_eq!(SerialNumber::from_report(&report).unwrap().serial, "PM2319H12345678");
+    }
+
+    #[test]
+    fn test_decode_device_mode() {
+        let report = report_with(GetDeviceMode.report(), &[0x03, 0x00]);
+        assert_eq!(DeviceMode::from_report(&report).unwrap(), DeviceMode::Driver);
+
+        let report = report_with(GetDeviceMode.report(), &[0x01, 0x00]);
+        assert_eq!(DeviceMode::from_report(&report), Err(ReportError::UnknownValue("device mode", 0x01)));
+
+        let set = SetDeviceMode { mode: DeviceMode::Normal }.report();
+        assert_eq!((set.command_id().raw(), set.arguments[..2].to_vec()), (0x04, vec![0x00, 0x00]));
+    }
+
+    #[test]
+    fn test_decode_idle_timeout() {
+        let report = report_with(GetIdleTimeout.report(), &[0x01, 0x2C]);
+        assert_eq!(IdleTimeout::from_report(&report).unwrap(), IdleTimeout { seconds: 300 });
+
+        assert_eq!(SetIdleTimeout::new(900).unwrap().arguments()[..2], [0x03, 0x84]);
+        assert!(SetIdleTimeout::new(59).is_err());
+        assert!(SetIdleTimeout::new(901).is_err());
+    }
+
+    #[test]
+    fn test_decode_low_battery_threshold() {
+        let report = report_with(GetChargeLowThreshold.report(), &[0x26]);
+        assert_eq!(LowBatteryThreshold::from_report(&report).unwrap().percentage(), 15);
+
+        for percentage in 5..=25 {
+            let set = SetChargeLowThreshold::from_percentage(percentage).unwrap().report();
+            assert_eq!(LowBatteryThreshold::from_report(&set).unwrap().percentage(), percentage);
+        }
+        assert_eq!(SetChargeLowThreshold::from_percentage(25).unwrap().arguments()[0], 0x3F);
+        assert!(SetChargeLowThreshold::from_percentage(30).is_err());
+    }
+
+    #[test]
+    fn test_decode_standard_led() {
+        let report = report_with(GetLedState { led_id: crate::LOGO_LED }.report(), &[0x01, 0x04, 0x01]);
+        assert_eq!(LedState::from_report(&report).unwrap(), LedState { led_id: 0x04, on: true });
+
+        let report = report_with(GetLedEffect { led_id: crate::LOGO_LED }.report(), &[0x01, 0x04, 0x02]);
+        assert_eq!(LedEffect::from_report(&report).unwrap().effect, RazerClassicEffectId::ClassicEffectBreathing);
+
+        let report = report_with(GetLedEffect { led_id: crate::LOGO_LED }.report(), &[0x01, 0x04, 0x03]);
+        assert_eq!(LedEffect::from_report(&report), Err(ReportError::UnknownValue("LED effect", 0x03)));
+    }
+}
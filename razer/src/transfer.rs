@@ -0,0 +1,131 @@
+/* Payloads that don't fit into the 80 argument bytes of a single report.
+ *
+ * Such a payload is sent as a sequence of reports of the same command, each carrying up to
+ * 80 bytes. `remaining_packets` counts down the reports still to come and is 0 on the last one,
+ * so the receiving side knows when the payload is complete and can tell if a report went missing.
+ * */
+
+use crate::error::ReportError;
+use crate::report::RazerReport;
+
+const CHUNK_LEN: usize = 80;
+
+/// Splits `payload` into reports of one command, in the order they have to be sent.
+///
+/// An empty payload still takes a single report without data.
+pub fn split_payload(command_class: u8, command_id: u8, payload: &[u8]) -> Result<Vec<RazerReport>, ReportError> {
+    let mut chunks: Vec<&[u8]> = payload.chunks(CHUNK_LEN).collect();
+    if chunks.is_empty() {
+        chunks.push(&[]);
+    }
+    if chunks.len() - 1 > u16::MAX as usize {
+        return Err(ReportError::InvalidPayload(format!("{} bytes don't fit into one transfer", payload.len())));
+    }
+
+    let last = chunks.len() - 1;
+    let reports = chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut arguments = [0u8; 80];
+            arguments[..chunk.len()].copy_from_slice(chunk);
+
+            let mut report = RazerReport::new(command_class, command_id, chunk.len() as u8, arguments);
+            report.set_remaining_packets((last - index) as u16);
+            report
+        })
+        .collect();
+
+    Ok(reports)
+}
+
+/// Joins the reports of a multi-packet transfer back into its payload.
+///
+/// Reports have to be pushed in the order they were received. Every one of them has to belong
+/// to the same command and count `remaining_packets` down by exactly one.
+#[derive(Debug, Default)]
+pub struct PayloadAssembler {
+    first: Option<RazerReport>,
+    remaining_packets: u16,
+    payload: Vec<u8>,
+}
+
+impl PayloadAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, report: &RazerReport) -> Result<(), ReportError> {
+        match &self.first {
+            None => self.first = Some(*report),
+            Some(first) => {
+                if self.remaining_packets == 0 {
+                    return Err(ReportError::InvalidPayload("report after the last one of the transfer".into()));
+                }
+                report.validate_response(first)?;
+                if report.remaining_packets() != self.remaining_packets - 1 {
+                    return Err(ReportError::PacketSequence(self.remaining_packets - 1, report.remaining_packets()));
+                }
+            }
+        }
+
+        let data_size = report.data_size() as usize;
+        if data_size > CHUNK_LEN {
+            return Err(ReportError::InvalidPayload(format!("data size {} exceeds {} bytes", data_size, CHUNK_LEN)));
+        }
+
+        self.remaining_packets = report.remaining_packets();
+        self.payload.extend_from_slice(&report.arguments[..data_size]);
+        Ok(())
+    }
+
+    /// Whether the last report of the transfer has been pushed.
+    pub fn is_complete(&self) -> bool {
+        self.first.is_some() && self.remaining_packets == 0
+    }
+
+    pub fn into_payload(self) -> Result<Vec<u8>, ReportError> {
+        if !self.is_complete() {
+            return Err(ReportError::InvalidPayload(format!("transfer incomplete, {} reports missing", self.remaining_packets)));
+        }
+        Ok(self.payload)
+    }
+}
+
+/// Joins a complete transfer, see `PayloadAssembler`.
+pub fn join_payload(reports: &[RazerReport]) -> Result<Vec<u8>, ReportError> {
+    let mut assembler = PayloadAssembler::new();
+    for report in reports {
+        assembler.push(report)?;
+    }
+    assembler.into_payload()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_and_join_roundtrip() {
+        let payload: Vec<u8> = (0..200u16).map(|b| b as u8).collect();
+        let reports = split_payload(0x06, 0x0C, &payload).unwrap();
+
+        assert_eq!(reports.len(), 3);
+        assert_eq!(
+            reports.iter().map(|r| (r.remaining_packets(), r.data_size())).collect::<Vec<_>>(),
+            vec![(2, 80), (1, 80), (0, 40)]
+        );
+        assert_eq!(join_payload(&reports).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_join_rejects_missing_report() {
+        let reports = split_payload(0x06, 0x0C, &[0xAA; 200]).unwrap();
+
+        assert_eq!(
+            join_payload(&[reports[0], reports[2]]),
+            Err(ReportError::PacketSequence(1, 0))
+        );
+        assert!(matches!(join_payload(&reports[..2]), Err(ReportError::InvalidPayload(_))));
+    }
+}